@@ -0,0 +1,162 @@
+//! Builder-style test data factories for `my-blog-backend`'s domain
+//! entities, so repository and integration tests build fixtures
+//! declaratively instead of hand-writing `NewArticle`/`Tag` literals (or
+//! reaching for ad hoc env state) in every test's setup.
+
+use chrono::Utc;
+use my_blog_backend::domain::entities::{ArticleLicense, ArticleStatus, NewArticle, Tag};
+use uuid::Uuid;
+
+/// Builds a [`NewArticle`] with sensible defaults, letting a test override
+/// only the fields it cares about. `with_tags` doesn't attach tags itself
+/// (that's a repository operation) — it records how many [`Tag`] fixtures
+/// [`Self::build_tags`] should hand back alongside the article.
+#[derive(Debug, Clone)]
+pub struct ArticleFactory {
+    user_id: Uuid,
+    title: String,
+    body: String,
+    status: Option<ArticleStatus>,
+    category_id: Option<Uuid>,
+    license: Option<ArticleLicense>,
+    attribution: Option<String>,
+    allow_duplicate: Option<bool>,
+    tag_count: usize,
+}
+
+impl Default for ArticleFactory {
+    fn default() -> Self {
+        Self {
+            user_id: Uuid::new_v4(),
+            title: "Fixture Article".to_string(),
+            body: "Fixture body content.".to_string(),
+            status: None,
+            category_id: None,
+            license: None,
+            attribution: None,
+            allow_duplicate: Some(true),
+            tag_count: 0,
+        }
+    }
+}
+
+impl ArticleFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn published() -> Self {
+        Self::new().status(ArticleStatus::Published)
+    }
+
+    pub fn draft() -> Self {
+        Self::new().status(ArticleStatus::Draft)
+    }
+
+    pub fn private() -> Self {
+        Self::new().status(ArticleStatus::Private)
+    }
+
+    pub fn user_id(mut self, user_id: Uuid) -> Self {
+        self.user_id = user_id;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn status(mut self, status: ArticleStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn category_id(mut self, category_id: Uuid) -> Self {
+        self.category_id = Some(category_id);
+        self
+    }
+
+    pub fn license(mut self, license: ArticleLicense) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    pub fn attribution(mut self, attribution: impl Into<String>) -> Self {
+        self.attribution = Some(attribution.into());
+        self
+    }
+
+    pub fn allow_duplicate(mut self, allow_duplicate: bool) -> Self {
+        self.allow_duplicate = Some(allow_duplicate);
+        self
+    }
+
+    /// Records that `count` [`Tag`] fixtures should accompany this article;
+    /// fetch them with [`Self::build_tags`] once the article itself exists.
+    pub fn with_tags(mut self, count: usize) -> Self {
+        self.tag_count = count;
+        self
+    }
+
+    pub fn build(self) -> NewArticle {
+        NewArticle {
+            user_id: self.user_id,
+            title: self.title,
+            body: self.body,
+            status: self.status,
+            category_id: self.category_id,
+            license: self.license,
+            attribution: self.attribution,
+            allow_duplicate: self.allow_duplicate,
+        }
+    }
+
+    pub fn build_tags(&self) -> Vec<Tag> {
+        (0..self.tag_count).map(|i| TagFactory::named(format!("fixture-tag-{i}")).build()).collect()
+    }
+}
+
+/// Builds a freestanding [`Tag`] fixture.
+#[derive(Debug, Clone)]
+pub struct TagFactory {
+    name: String,
+}
+
+impl Default for TagFactory {
+    fn default() -> Self {
+        Self {
+            name: "fixture-tag".to_string(),
+        }
+    }
+}
+
+impl TagFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::new().name(name)
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn build(self) -> Tag {
+        let now = Utc::now();
+        Tag {
+            id: Uuid::new_v4(),
+            name: self.name,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::env;
+
+/// Configures the optional external captioning integration that suggests
+/// alt text for uploaded images, loaded from `AUTO_ALT_TEXT_ENABLED` /
+/// `AUTO_ALT_TEXT_API_URL` / `AUTO_ALT_TEXT_API_KEY`. `api_url` is `None`
+/// unless the feature flag is on and a URL was actually configured, so
+/// callers can tell "disabled" apart from "misconfigured" by checking it
+/// rather than a separate flag.
+#[derive(Debug, Clone, Default)]
+pub struct AltTextConfig {
+    pub api_url: Option<String>,
+    pub api_key: String,
+}
+
+impl AltTextConfig {
+    pub fn from_env() -> Self {
+        let enabled = env::var("AUTO_ALT_TEXT_ENABLED")
+            .map(|v| v == "true")
+            .unwrap_or(false);
+
+        Self {
+            api_url: if enabled { env::var("AUTO_ALT_TEXT_API_URL").ok() } else { None },
+            api_key: env::var("AUTO_ALT_TEXT_API_KEY").unwrap_or_default(),
+        }
+    }
+}
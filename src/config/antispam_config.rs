@@ -0,0 +1,44 @@
+use std::env;
+
+const DEFAULT_PENDING_THRESHOLD: f32 = 0.3;
+const DEFAULT_SPAM_THRESHOLD: f32 = 0.7;
+
+/// Configures the optional Akismet integration that scores new comments for
+/// spam, loaded from `ANTISPAM_AKISMET_ENABLED` / `ANTISPAM_AKISMET_API_KEY`.
+/// `akismet_api_key` is `None` unless the feature flag is on and a key was
+/// actually configured, so callers can tell "disabled" apart from
+/// "misconfigured" by checking it rather than a separate flag. The local
+/// heuristic scorer is always available and used whenever Akismet isn't.
+#[derive(Debug, Clone)]
+pub struct AntispamConfig {
+    pub akismet_api_key: Option<String>,
+    /// The site's own URL, required by the Akismet API to identify the
+    /// front end the comment was submitted to.
+    pub site_url: String,
+    /// Scores at or above this are routed to pending moderation rather than
+    /// published immediately.
+    pub pending_threshold: f32,
+    /// Scores at or above this are routed straight to the spam state.
+    pub spam_threshold: f32,
+}
+
+impl AntispamConfig {
+    pub fn from_env() -> Self {
+        let enabled = env::var("ANTISPAM_AKISMET_ENABLED")
+            .map(|v| v == "true")
+            .unwrap_or(false);
+
+        Self {
+            akismet_api_key: if enabled { env::var("ANTISPAM_AKISMET_API_KEY").ok() } else { None },
+            site_url: env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| "http://localhost:8080".to_string()),
+            pending_threshold: env::var("ANTISPAM_PENDING_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_PENDING_THRESHOLD),
+            spam_threshold: env::var("ANTISPAM_SPAM_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_SPAM_THRESHOLD),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::env;
+
+use crate::config::{
+    AltTextConfig, AntispamConfig, CommentPrivacyConfig, ConcurrencyLimitConfig, CorsConfig, ImageProxyConfig,
+    LoggingConfig, ObjectStorageConfig, PermalinkConfig,
+};
+
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub database_url: String,
+    pub server_port: u16,
+    pub jwt_secret: String,
+    pub concurrency_limit: ConcurrencyLimitConfig,
+    pub comment_privacy: CommentPrivacyConfig,
+    pub antispam: AntispamConfig,
+    pub logging: LoggingConfig,
+    pub cors: CorsConfig,
+    /// Absolute origin this server is reachable at, used to build absolute
+    /// links (sitemaps) that can't be relative.
+    pub public_base_url: String,
+    pub image_proxy: ImageProxyConfig,
+    pub object_storage: ObjectStorageConfig,
+    pub alt_text: AltTextConfig,
+    pub permalink: PermalinkConfig,
+    /// How many reverse proxy hops in front of this server are trusted to
+    /// append an honest entry to `X-Forwarded-For`. `0` (the default) means
+    /// no proxy is trusted and the header is ignored entirely in favor of
+    /// the TCP peer address; a client can set any header it likes, so
+    /// trusting it without a known hop count would let a caller spoof its
+    /// own rate-limit bucket or abuse-signal IP.
+    pub trusted_proxy_hops: usize,
+}
+
+impl AppConfig {
+    pub fn from_env() -> Self {
+        AppConfig {
+            database_url: env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
+            server_port: env::var("SERVER_PORT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(8080),
+            jwt_secret: env::var("JWT_SECRET").expect("JWT_SECRET must be set"),
+            concurrency_limit: ConcurrencyLimitConfig::from_env(),
+            comment_privacy: CommentPrivacyConfig::from_env(),
+            antispam: AntispamConfig::from_env(),
+            logging: LoggingConfig::from_env(),
+            cors: CorsConfig::from_env(),
+            public_base_url: env::var("PUBLIC_BASE_URL")
+                .unwrap_or_else(|_| "http://localhost:8080".to_string()),
+            image_proxy: ImageProxyConfig::from_env(),
+            object_storage: ObjectStorageConfig::from_env(),
+            alt_text: AltTextConfig::from_env(),
+            permalink: PermalinkConfig::from_env(),
+            trusted_proxy_hops: env::var("TRUSTED_PROXY_HOPS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+        }
+    }
+}
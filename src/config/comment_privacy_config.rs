@@ -0,0 +1,35 @@
+use std::env;
+use std::time::Duration;
+
+const DEFAULT_IP_RETENTION_DAYS: i64 = 30;
+
+/// How long a comment's hashed IP and user agent are kept before a cleanup
+/// pass scrubs them, loaded from `COMMENT_PRIVACY_IP_RETENTION_DAYS`. The
+/// comment itself and its body are unaffected; only the abuse-investigation
+/// fields are cleared.
+#[derive(Debug, Clone)]
+pub struct CommentPrivacyConfig {
+    pub ip_retention: Duration,
+}
+
+impl CommentPrivacyConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+
+        Self {
+            ip_retention: env::var("COMMENT_PRIVACY_IP_RETENTION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(|days: u64| Duration::from_secs(days * 24 * 60 * 60))
+                .unwrap_or(default.ip_retention),
+        }
+    }
+}
+
+impl Default for CommentPrivacyConfig {
+    fn default() -> Self {
+        Self {
+            ip_retention: Duration::from_secs(DEFAULT_IP_RETENTION_DAYS as u64 * 24 * 60 * 60),
+        }
+    }
+}
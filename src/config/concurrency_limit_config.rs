@@ -0,0 +1,39 @@
+use std::env;
+use std::time::Duration;
+
+/// Caps the number of requests the service handles at once, loaded from
+/// `CONCURRENCY_LIMIT_MAX_IN_FLIGHT` / `CONCURRENCY_LIMIT_QUEUE_TIMEOUT_MS`,
+/// so a traffic spike queues briefly and then sheds load instead of
+/// exhausting the database connection pool.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimitConfig {
+    pub max_in_flight: u32,
+    pub queue_timeout: Duration,
+}
+
+impl ConcurrencyLimitConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+
+        Self {
+            max_in_flight: env::var("CONCURRENCY_LIMIT_MAX_IN_FLIGHT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.max_in_flight),
+            queue_timeout: env::var("CONCURRENCY_LIMIT_QUEUE_TIMEOUT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(default.queue_timeout),
+        }
+    }
+}
+
+impl Default for ConcurrencyLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 256,
+            queue_timeout: Duration::from_millis(5000),
+        }
+    }
+}
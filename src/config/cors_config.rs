@@ -0,0 +1,25 @@
+use std::env;
+
+/// Origins allowed to make cross-origin requests, loaded from a
+/// comma-separated `CORS_ALLOWED_ORIGINS` env var. Empty means no
+/// cross-origin requests are allowed.
+#[derive(Debug, Clone, Default)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+}
+
+impl CorsConfig {
+    pub fn from_env() -> Self {
+        let allowed_origins = env::var("CORS_ALLOWED_ORIGINS")
+            .ok()
+            .map(|raw| {
+                raw.split(',')
+                    .map(|origin| origin.trim().to_string())
+                    .filter(|origin| !origin.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { allowed_origins }
+    }
+}
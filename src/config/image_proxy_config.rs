@@ -0,0 +1,17 @@
+use std::env;
+
+/// Configures an optional CDN/image-proxy (Cloudflare Images, imgproxy) to
+/// serve images through instead of their stored URL directly, loaded from
+/// `IMAGE_PROXY_BASE_URL`. Unset means images are served locally, unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct ImageProxyConfig {
+    pub base_url: Option<String>,
+}
+
+impl ImageProxyConfig {
+    pub fn from_env() -> Self {
+        Self {
+            base_url: env::var("IMAGE_PROXY_BASE_URL").ok(),
+        }
+    }
+}
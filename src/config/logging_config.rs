@@ -0,0 +1,52 @@
+use std::env;
+
+/// Controls the optional rotating file-appender output for tracing, used
+/// alongside stdout on deployments that have no external log collector.
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    pub file_output: Option<FileLoggingConfig>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileLoggingConfig {
+    pub directory: String,
+    pub file_name_prefix: String,
+    pub rotation: LogRotation,
+    /// Number of rotated files to keep before the oldest is deleted.
+    pub max_files: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogRotation {
+    Daily,
+    Hourly,
+    Never,
+}
+
+impl LoggingConfig {
+    pub fn from_env() -> Self {
+        let Ok(directory) = env::var("LOG_DIR") else {
+            return LoggingConfig { file_output: None };
+        };
+
+        let rotation = match env::var("LOG_ROTATION").as_deref() {
+            Ok("hourly") => LogRotation::Hourly,
+            Ok("never") => LogRotation::Never,
+            _ => LogRotation::Daily,
+        };
+
+        let max_files = env::var("LOG_MAX_FILES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(14);
+
+        LoggingConfig {
+            file_output: Some(FileLoggingConfig {
+                directory,
+                file_name_prefix: env::var("LOG_FILE_PREFIX").unwrap_or_else(|_| "my-blog-backend".to_string()),
+                rotation,
+                max_files,
+            }),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+mod alt_text_config;
+mod antispam_config;
+mod app_config;
+mod comment_privacy_config;
+mod concurrency_limit_config;
+mod cors_config;
+mod image_proxy_config;
+mod logging_config;
+mod object_storage_config;
+mod permalink_config;
+mod rate_limit_config;
+
+pub use alt_text_config::AltTextConfig;
+pub use antispam_config::AntispamConfig;
+pub use app_config::AppConfig;
+pub use comment_privacy_config::CommentPrivacyConfig;
+pub use concurrency_limit_config::ConcurrencyLimitConfig;
+pub use cors_config::CorsConfig;
+pub use image_proxy_config::ImageProxyConfig;
+pub use logging_config::{FileLoggingConfig, LogRotation, LoggingConfig};
+pub use object_storage_config::ObjectStorageConfig;
+pub use permalink_config::{PermalinkConfig, PermalinkStrategy};
+pub use rate_limit_config::{RateLimitConfig, RateLimitOverride, RateLimitRule};
@@ -0,0 +1,47 @@
+use std::env;
+use std::time::Duration;
+
+/// Configures the S3-compatible bucket large image uploads are presigned
+/// against, loaded from `OBJECT_STORAGE_BUCKET` / `OBJECT_STORAGE_REGION` /
+/// `OBJECT_STORAGE_ACCESS_KEY_ID` / `OBJECT_STORAGE_SECRET_ACCESS_KEY` /
+/// `OBJECT_STORAGE_PRESIGN_TTL_SECONDS`. `bucket` is `None` when direct
+/// uploads aren't configured, in which case presign/confirm requests are
+/// rejected rather than silently falling back to the proxied upload path.
+#[derive(Debug, Clone)]
+pub struct ObjectStorageConfig {
+    pub bucket: Option<String>,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub presign_ttl: Duration,
+}
+
+impl ObjectStorageConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+
+        Self {
+            bucket: env::var("OBJECT_STORAGE_BUCKET").ok(),
+            region: env::var("OBJECT_STORAGE_REGION").unwrap_or(default.region),
+            access_key_id: env::var("OBJECT_STORAGE_ACCESS_KEY_ID").unwrap_or_default(),
+            secret_access_key: env::var("OBJECT_STORAGE_SECRET_ACCESS_KEY").unwrap_or_default(),
+            presign_ttl: env::var("OBJECT_STORAGE_PRESIGN_TTL_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(default.presign_ttl),
+        }
+    }
+}
+
+impl Default for ObjectStorageConfig {
+    fn default() -> Self {
+        Self {
+            bucket: None,
+            region: "us-east-1".to_string(),
+            access_key_id: String::new(),
+            secret_access_key: String::new(),
+            presign_ttl: Duration::from_secs(900),
+        }
+    }
+}
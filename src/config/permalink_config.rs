@@ -0,0 +1,26 @@
+use std::env;
+
+/// Which shape of URL [`crate::presentation::link_builder::LinkBuilder`]
+/// produces for an article permalink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermalinkStrategy {
+    /// `/articles/{uuid}`, stable even if the article is retitled.
+    Uuid,
+    /// `/articles/{slug}`, friendlier but changes if the title does.
+    Slug,
+}
+
+#[derive(Debug, Clone)]
+pub struct PermalinkConfig {
+    pub strategy: PermalinkStrategy,
+}
+
+impl PermalinkConfig {
+    pub fn from_env() -> Self {
+        let strategy = match env::var("PERMALINK_STRATEGY").as_deref() {
+            Ok("slug") => PermalinkStrategy::Slug,
+            _ => PermalinkStrategy::Uuid,
+        };
+        Self { strategy }
+    }
+}
@@ -0,0 +1,120 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Structured rate limit configuration, loaded from a TOML file so ops can
+/// tune limits per route without a code change.
+///
+/// Overrides are matched in order against `(method, path_pattern)`; the
+/// first match wins, falling back to `default` when nothing matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    pub default: RateLimitRule,
+    #[serde(default)]
+    pub overrides: Vec<RateLimitOverride>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitRule {
+    pub requests_per_minute: u32,
+    /// Multiplier applied to `requests_per_minute` for authenticated callers.
+    #[serde(default = "default_authenticated_multiplier")]
+    pub authenticated_multiplier: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitOverride {
+    /// A route path as registered with the router, e.g. `/articles/:article_id`.
+    pub path_pattern: String,
+    /// HTTP method this override applies to, e.g. `GET`. `*` matches any method.
+    #[serde(default = "default_method")]
+    pub method: String,
+    #[serde(flatten)]
+    pub rule: RateLimitRule,
+}
+
+fn default_authenticated_multiplier() -> f64 {
+    1.0
+}
+
+fn default_method() -> String {
+    "*".to_string()
+}
+
+impl RateLimitConfig {
+    /// Loads from `RATE_LIMIT_CONFIG_PATH` if set, falling back to
+    /// [`Default::default`] otherwise. Shared by startup and by
+    /// [`crate::infrastructure::runtime_config::RuntimeSettings::from_env`]
+    /// so a reload can't pick up different rules than a fresh boot would.
+    pub fn from_env() -> Self {
+        match std::env::var("RATE_LIMIT_CONFIG_PATH") {
+            Ok(path) => {
+                Self::from_file(&path).unwrap_or_else(|e| panic!("invalid rate limit config at {path}: {e}"))
+            }
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| anyhow::anyhow!("failed to read rate limit config {:?}: {e}", path.as_ref()))?;
+        let config: RateLimitConfig = toml::from_str(&contents)
+            .map_err(|e| anyhow::anyhow!("failed to parse rate limit config {:?}: {e}", path.as_ref()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        self.default.validate("default")?;
+        for (i, o) in self.overrides.iter().enumerate() {
+            if o.path_pattern.is_empty() {
+                anyhow::bail!("rate limit override #{i} has an empty path_pattern");
+            }
+            o.rule.validate(&format!("override #{i} ({})", o.path_pattern))?;
+        }
+        Ok(())
+    }
+
+    /// Finds the first override matching `method` and `path`, or falls back
+    /// to the default rule.
+    pub fn rule_for(&self, method: &str, path: &str) -> &RateLimitRule {
+        self.overrides
+            .iter()
+            .find(|o| (o.method == "*" || o.method.eq_ignore_ascii_case(method)) && o.path_pattern == path)
+            .map(|o| &o.rule)
+            .unwrap_or(&self.default)
+    }
+}
+
+impl RateLimitRule {
+    fn validate(&self, label: &str) -> anyhow::Result<()> {
+        if self.requests_per_minute == 0 {
+            anyhow::bail!("{label}: requests_per_minute must be greater than zero");
+        }
+        if self.authenticated_multiplier <= 0.0 {
+            anyhow::bail!("{label}: authenticated_multiplier must be greater than zero");
+        }
+        Ok(())
+    }
+
+    pub fn effective_limit(&self, authenticated: bool) -> u32 {
+        if authenticated {
+            ((self.requests_per_minute as f64) * self.authenticated_multiplier).round() as u32
+        } else {
+            self.requests_per_minute
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        RateLimitConfig {
+            default: RateLimitRule {
+                requests_per_minute: 60,
+                authenticated_multiplier: 5.0,
+            },
+            overrides: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::time::{Duration, Instant};
+
+/// The point in time by which this request's response should have been
+/// sent, set once per request by `propagate_deadline` middleware and
+/// carried through to any repository call that wants to respect it.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestDeadline(pub Instant);
+
+impl RequestDeadline {
+    /// Time left before the deadline, floored at one millisecond so a
+    /// statement timeout of `0` (meaning "no timeout" to Postgres) is never
+    /// produced for an already-expired deadline.
+    pub fn remaining(&self) -> Duration {
+        self.0.saturating_duration_since(Instant::now()).max(Duration::from_millis(1))
+    }
+}
@@ -0,0 +1,13 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// One day's worth of aggregated business metrics, as produced by the
+/// analytics export.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DailyMetrics {
+    pub day: NaiveDate,
+    pub views: i64,
+    pub reactions: i64,
+    pub comments: i64,
+    pub signups: i64,
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{AuthorProfile, CommentThreadSummary, Tag};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "article_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleStatus {
+    Draft,
+    Private,
+    Published,
+}
+
+/// How a reader may reuse an article's content, surfaced so multi-author
+/// blogs can mark content licensed more permissively than the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "article_license", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ArticleLicense {
+    AllRightsReserved,
+    CcBy,
+    CcBySa,
+    CcByNc,
+    CcByNd,
+    CcByNcSa,
+    CcByNcNd,
+    Cc0,
+    PublicDomain,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Article {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub status: ArticleStatus,
+    pub category_id: Option<Uuid>,
+    pub license: ArticleLicense,
+    /// Credit line to show alongside the license, e.g. the original
+    /// author's name and a link, for content that isn't all-rights-reserved.
+    pub attribution: Option<String>,
+    /// Derived from `title`/`body`; see [`content_derivation`](crate::infrastructure::content_derivation).
+    pub slug: Option<String>,
+    pub word_count: Option<i32>,
+    pub excerpt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewArticle {
+    pub user_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub status: Option<ArticleStatus>,
+    pub category_id: Option<Uuid>,
+    pub license: Option<ArticleLicense>,
+    pub attribution: Option<String>,
+    /// Skips the duplicate-content warning; set when the author has
+    /// already seen the similar-article list and wants to create anyway.
+    pub allow_duplicate: Option<bool>,
+}
+
+/// An article with its tags, author profile and comment thread summary,
+/// for a single round-trip detail view instead of a separate
+/// tags/author/comments request per article.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArticleWithTags {
+    pub article: Article,
+    pub tags: Vec<Tag>,
+    pub author: AuthorProfile,
+    pub comment_summary: CommentThreadSummary,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// An advisory edit lock on an article: while it's held and unexpired,
+/// [`crate::usecase::ArticleAppService::update`] rejects edits from anyone
+/// but `owner_id`. Purely advisory until CRDT-style collaborative editing
+/// exists — nothing stops a client from ignoring it.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ArticleLock {
+    pub article_id: Uuid,
+    pub owner_id: Uuid,
+    pub acquired_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
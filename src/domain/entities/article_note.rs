@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A private note an article's author leaves for themselves, never shown
+/// alongside the published article or returned from any public listing
+/// path.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArticleNote {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewArticleNote {
+    pub article_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::ArticleLicense;
+
+/// A not-yet-published edit to an already-published article. Readers keep
+/// seeing the `Article` row's own fields until [`crate::usecase::ArticleAppService::publish_pending`]
+/// copies this revision over it; until then, this is the only place the
+/// edit exists.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArticlePendingRevision {
+    pub article_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub category_id: Option<Uuid>,
+    pub license: ArticleLicense,
+    pub attribution: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewArticlePendingRevision {
+    pub article_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub category_id: Option<Uuid>,
+    pub license: ArticleLicense,
+    pub attribution: Option<String>,
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A slug an article used to have, kept around so a link or bookmark made
+/// before a rename still resolves instead of 404ing.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ArticleSlugRedirect {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub old_slug: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct AuditLog {
+    pub id: Uuid,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub detail: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAuditLog {
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub detail: Option<String>,
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Block {
+    pub id: Uuid,
+    pub author_id: Uuid,
+    pub blocked_user_id: Option<Uuid>,
+    pub blocked_guest_fingerprint: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewBlock {
+    pub author_id: Uuid,
+    pub blocked_user_id: Option<Uuid>,
+    pub blocked_guest_fingerprint: Option<String>,
+}
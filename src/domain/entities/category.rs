@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Article, Tag};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Category {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Everything a category landing page needs in one response: the category
+/// itself, its size, a taste of its latest content, and its most common
+/// tags.
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryOverview {
+    pub category: Category,
+    pub article_count: i64,
+    pub latest_articles: Vec<Article>,
+    pub top_tags: Vec<Tag>,
+    /// Always empty today: categories have no parent/child hierarchy yet.
+    /// Kept so a future hierarchy can populate it without breaking this
+    /// response shape.
+    pub child_categories: Vec<Category>,
+}
+
+/// Outcome of one article in a bulk category assign/remove request.
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryAssignmentResult {
+    pub article_id: Uuid,
+    pub success: bool,
+    pub error: Option<String>,
+}
@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Where a comment sits in the antispam moderation workflow, set from the
+/// score [`crate::infrastructure::antispam_scorer::AntispamScorer`] returns
+/// when it's created. Only `Visible` comments are shown to the public;
+/// `Pending` and `Spam` are held for an admin to confirm or release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "comment_moderation_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum CommentModerationStatus {
+    Visible,
+    Pending,
+    Spam,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Comment {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    /// The comment this one is a reply to, if any. Kept on tombstoned
+    /// comments so the thread doesn't collapse when an ancestor is deleted.
+    pub parent_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub guest_name: Option<String>,
+    pub guest_fingerprint: Option<String>,
+    /// SHA-256 hash of the guest's email address. The address itself is
+    /// never persisted; only the hash (for verification-token matching) and
+    /// whether it's been verified are kept.
+    pub guest_email_hash: Option<String>,
+    pub guest_email_verified_at: Option<DateTime<Utc>>,
+    pub body: String,
+    /// The id this comment carried under the pre-UUID schema, if it was
+    /// migrated from there. `None` for every comment created natively with
+    /// a UUID id.
+    pub legacy_id: Option<i32>,
+    pub moderation_status: CommentModerationStatus,
+    /// Hex-encoded SHA-256 of the IP address the comment was submitted
+    /// from, kept for abuse investigation and scrubbed after
+    /// [`crate::config::CommentPrivacyConfig::ip_retention`]. Never
+    /// returned outside admin moderation views; see
+    /// [`CommentModerationDetail`].
+    #[serde(skip_serializing)]
+    pub ip_hash: Option<String>,
+    /// The `User-Agent` header the comment was submitted with, truncated
+    /// to [`MAX_USER_AGENT_LENGTH`] characters. Scrubbed on the same
+    /// schedule as `ip_hash`, and never returned outside admin moderation
+    /// views.
+    #[serde(skip_serializing)]
+    pub user_agent: Option<String>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The longest user agent string persisted; longer ones are truncated
+/// before storage so a malicious or unusually long header can't bloat a
+/// row.
+pub const MAX_USER_AGENT_LENGTH: usize = 255;
+
+/// The abuse-investigation fields withheld from [`Comment`]'s normal JSON
+/// representation, surfaced only to admins via a dedicated moderation
+/// endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentModerationDetail {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub ip_hash: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Comment> for CommentModerationDetail {
+    fn from(comment: &Comment) -> Self {
+        CommentModerationDetail {
+            id: comment.id,
+            article_id: comment.article_id,
+            ip_hash: comment.ip_hash.clone(),
+            user_agent: comment.user_agent.clone(),
+            created_at: comment.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewComment {
+    pub article_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub guest_name: Option<String>,
+    pub guest_fingerprint: Option<String>,
+    /// Raw guest email, used only to send a verification link; never
+    /// persisted and not read by the repository (see `guest_email_hash`).
+    pub guest_email: Option<String>,
+    /// Hash of `guest_email`, filled in by [`crate::usecase::CommentAppService`]
+    /// before the comment reaches the repository.
+    pub guest_email_hash: Option<String>,
+    pub body: String,
+    /// Raw client IP address, used only to compute `ip_hash`; never
+    /// persisted or read by the repository (see `ip_hash`).
+    pub client_ip: Option<String>,
+    /// Hash of `client_ip`, filled in by [`crate::usecase::CommentAppService`]
+    /// before the comment reaches the repository.
+    pub ip_hash: Option<String>,
+    /// Raw `User-Agent` header, truncated by
+    /// [`crate::usecase::CommentAppService`] before the comment reaches the
+    /// repository.
+    pub user_agent: Option<String>,
+    /// Spam-likelihood verdict, filled in by
+    /// [`crate::usecase::CommentAppService`] from the configured
+    /// [`crate::infrastructure::antispam_scorer::AntispamScorer`] before
+    /// the comment reaches the repository.
+    pub moderation_status: Option<CommentModerationStatus>,
+}
+
+/// Thread-level metadata for an article's comments, computed via a single
+/// aggregate query so an article response can show "32 comments, last
+/// activity 2h ago" without fetching the thread itself. Excludes
+/// soft-deleted (tombstoned) comments.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct CommentThreadSummary {
+    pub total: i64,
+    pub top_level_count: i64,
+    pub latest_comment_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ContactMessage {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub message: String,
+    /// Set from the same [`crate::infrastructure::antispam_scorer::AntispamScorer`]
+    /// used for comments; spam messages are still stored (for the admin
+    /// listing to audit) but excluded from delivery.
+    pub is_spam: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewContactMessage {
+    pub name: String,
+    pub email: String,
+    pub message: String,
+    /// Raw client IP address, used only to score the submission; never
+    /// persisted. Filled in by [`crate::presentation::routes::contact`]
+    /// from request headers, same as [`crate::domain::entities::NewComment`].
+    pub client_ip: Option<String>,
+    /// Raw `User-Agent` header, used only to score the submission; never
+    /// persisted.
+    pub user_agent: Option<String>,
+}
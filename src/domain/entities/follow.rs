@@ -0,0 +1,13 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Whether `viewer_id` follows a profile, alongside the profile's current
+/// follower/following counts; returned by the follow/unfollow endpoints so
+/// the frontend can update its button state without a second request.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct FollowStatus {
+    pub followed_id: Uuid,
+    pub is_following: bool,
+    pub follower_count: i64,
+    pub following_count: i64,
+}
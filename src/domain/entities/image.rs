@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "image_processing_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ImageProcessingStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Image {
+    pub id: Uuid,
+    pub article_id: Option<Uuid>,
+    pub user_id: Uuid,
+    pub url: String,
+    pub mime_type: Option<String>,
+    /// Variant/transcode generation status. New uploads start `pending` and
+    /// move to `ready` or `failed` once the background job finishes.
+    pub processing_status: ImageProcessingStatus,
+    /// Alt text proposed by the automatic captioning integration, if one is
+    /// configured; the author may accept it as-is or override it. `None`
+    /// until the background suggestion job finishes, or forever if
+    /// captioning isn't enabled.
+    pub suggested_alt_text: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The outcome of deleting one image as part of a bulk operation, e.g.
+/// [`crate::usecase::ImageAppService::delete_by_article`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageDeletionResult {
+    pub image_id: Uuid,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Filters and pagination for browsing a user's personal media library.
+#[derive(Debug, Clone, Default)]
+pub struct ImageListFilter {
+    pub attached: Option<bool>,
+    pub mime_type: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: i64,
+    pub offset: i64,
+}
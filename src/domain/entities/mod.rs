@@ -0,0 +1,36 @@
+pub mod analytics;
+pub mod article;
+pub mod article_lock;
+pub mod article_note;
+pub mod article_pending_revision;
+pub mod article_slug_redirect;
+pub mod audit_log;
+pub mod block;
+pub mod category;
+pub mod comment;
+pub mod contact_message;
+pub mod follow;
+pub mod image;
+pub mod sitemap;
+pub mod tag;
+pub mod user;
+
+pub use analytics::DailyMetrics;
+pub use article::{Article, ArticleLicense, ArticleStatus, ArticleWithTags, NewArticle};
+pub use article_lock::ArticleLock;
+pub use article_note::{ArticleNote, NewArticleNote};
+pub use article_pending_revision::{ArticlePendingRevision, NewArticlePendingRevision};
+pub use article_slug_redirect::ArticleSlugRedirect;
+pub use audit_log::{AuditLog, NewAuditLog};
+pub use block::{Block, NewBlock};
+pub use category::{Category, CategoryAssignmentResult, CategoryOverview};
+pub use comment::{
+    Comment, CommentModerationDetail, CommentModerationStatus, CommentThreadSummary, NewComment,
+    MAX_USER_AGENT_LENGTH,
+};
+pub use contact_message::{ContactMessage, NewContactMessage};
+pub use follow::FollowStatus;
+pub use image::{Image, ImageDeletionResult, ImageListFilter, ImageProcessingStatus};
+pub use sitemap::SitemapEntry;
+pub use tag::{Tag, TagAttachmentResult};
+pub use user::{AuthorProfile, User, UserPreferences};
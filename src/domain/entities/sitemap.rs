@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Enough information about a published article to render one `<url>`
+/// element in a sitemap.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SitemapEntry {
+    pub article_id: Uuid,
+    pub slug: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Tag {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Outcome of one article in a bulk tag attach/detach request.
+#[derive(Debug, Clone, Serialize)]
+pub struct TagAttachmentResult {
+    pub article_id: Uuid,
+    pub success: bool,
+    pub error: Option<String>,
+}
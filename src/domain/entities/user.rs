@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::ArticleStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct User {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
+    pub default_article_status: ArticleStatus,
+    pub default_category_id: Option<Uuid>,
+    pub is_admin: bool,
+    pub is_verified: bool,
+    pub bio: Option<String>,
+    pub website: Option<String>,
+    pub social_links: Vec<String>,
+    /// IANA timezone name (e.g. `"America/New_York"`), used to localize
+    /// timestamps shown to this user; see
+    /// [`crate::infrastructure::datetime_format`].
+    pub timezone: String,
+    /// Locale tag (e.g. `"en"`, `"ja"`) used alongside `timezone` to format
+    /// dates for this user.
+    pub locale: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A user's content-creation defaults, exposed separately from the full
+/// user record via the preferences endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPreferences {
+    pub default_article_status: ArticleStatus,
+    pub default_category_id: Option<Uuid>,
+    /// See [`User::timezone`]. API timestamps themselves stay UTC; this only
+    /// tells frontends and emails how to display them to this user.
+    pub timezone: String,
+    pub locale: String,
+}
+
+impl From<&User> for UserPreferences {
+    fn from(user: &User) -> Self {
+        UserPreferences {
+            default_article_status: user.default_article_status,
+            default_category_id: user.default_category_id,
+            timezone: user.timezone.clone(),
+            locale: user.locale.clone(),
+        }
+    }
+}
+
+/// A user's public-facing identity: the subset of [`User`] safe to show to
+/// other visitors, for profile pages and article author embeds. Excludes
+/// `email` and `password_hash`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorProfile {
+    pub id: Uuid,
+    pub name: String,
+    pub is_verified: bool,
+    pub bio: Option<String>,
+    pub website: Option<String>,
+    pub social_links: Vec<String>,
+    pub follower_count: i64,
+    pub following_count: i64,
+}
+
+impl AuthorProfile {
+    /// Builds a public profile; `follower_count`/`following_count` must be
+    /// fetched from [`crate::domain::repository::FollowRepository`]
+    /// separately since they aren't columns on `users`.
+    pub fn new(user: &User, follower_count: i64, following_count: i64) -> Self {
+        AuthorProfile {
+            id: user.id,
+            name: user.name.clone(),
+            is_verified: user.is_verified,
+            bio: user.bio.clone(),
+            website: user.website.clone(),
+            social_links: user.social_links.clone(),
+            follower_count,
+            following_count,
+        }
+    }
+}
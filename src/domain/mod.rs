@@ -0,0 +1,5 @@
+pub mod deadline;
+pub mod entities;
+pub mod pagination;
+pub mod repository;
+pub mod validation;
@@ -0,0 +1,66 @@
+use serde::Serialize;
+
+/// A page of results shaped the way a cursor-paginated endpoint should
+/// return them: this page's rows, whether another page follows, and (if
+/// so) the cursor to request it with.
+#[derive(Debug, Clone, Serialize)]
+pub struct PagedBody<T> {
+    pub items: Vec<T>,
+    pub has_next: bool,
+    pub next_cursor: Option<String>,
+}
+
+/// Trims an over-fetched page down to `per_page` rows, deriving `has_next`
+/// and `next_cursor` from it. Callers fetch `per_page + 1` rows ordered by
+/// the cursor column and hand the raw `Vec<T>` here instead of each
+/// re-deriving the same has-next/next-cursor logic by hand.
+pub fn paginate<T>(mut rows: Vec<T>, per_page: usize, cursor_of: impl Fn(&T) -> String) -> PagedBody<T> {
+    let has_next = rows.len() > per_page;
+    if has_next {
+        rows.truncate(per_page);
+    }
+    let next_cursor = if has_next { rows.last().map(cursor_of) } else { None };
+
+    PagedBody {
+        items: rows,
+        has_next,
+        next_cursor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_page() {
+        let page = paginate::<i32>(vec![], 10, |n| n.to_string());
+        assert!(page.items.is_empty());
+        assert!(!page.has_next);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn fewer_rows_than_a_full_page() {
+        let page = paginate(vec![1, 2], 10, |n| n.to_string());
+        assert_eq!(page.items, vec![1, 2]);
+        assert!(!page.has_next);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn exact_page_boundary_has_no_next_page() {
+        let page = paginate(vec![1, 2, 3], 3, |n| n.to_string());
+        assert_eq!(page.items, vec![1, 2, 3]);
+        assert!(!page.has_next);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn one_extra_row_signals_a_next_page() {
+        let page = paginate(vec![1, 2, 3, 4], 3, |n| n.to_string());
+        assert_eq!(page.items, vec![1, 2, 3]);
+        assert!(page.has_next);
+        assert_eq!(page.next_cursor, Some("3".to_string()));
+    }
+}
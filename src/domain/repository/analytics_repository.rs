@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::stream::BoxStream;
+
+use crate::domain::entities::DailyMetrics;
+use crate::errors::{AppError, AppResult};
+
+#[async_trait]
+pub trait AnalyticsRepository: Send + Sync {
+    /// Streams one row per day in `[from, to]`, ordered ascending, without
+    /// materializing the whole window in memory.
+    fn stream_daily_metrics(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> BoxStream<'static, Result<DailyMetrics, AppError>>;
+
+    /// Deletes up to `batch_size` raw view events older than `cutoff`,
+    /// returning how many were removed.
+    async fn delete_view_events_older_than(&self, cutoff: DateTime<Utc>, batch_size: i64) -> AppResult<u64>;
+}
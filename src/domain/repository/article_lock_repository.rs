@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::ArticleLock;
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait ArticleLockRepository: Send + Sync {
+    /// The article's lock, unless none exists or it has expired.
+    async fn find_active(&self, article_id: Uuid) -> AppResult<Option<ArticleLock>>;
+    /// Acquires the lock for `owner_id`, renewing it if `owner_id` already
+    /// holds it or taking over if the existing lock has expired. Returns
+    /// `None` without changing anything if another owner currently holds
+    /// an unexpired lock.
+    async fn acquire(&self, article_id: Uuid, owner_id: Uuid, expires_at: DateTime<Utc>) -> AppResult<Option<ArticleLock>>;
+    /// Releases the lock, if `owner_id` holds it. A no-op otherwise.
+    async fn release(&self, article_id: Uuid, owner_id: Uuid) -> AppResult<()>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleNote, NewArticleNote};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait ArticleNoteRepository: Send + Sync {
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<ArticleNote>>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<ArticleNote>>;
+    async fn create(&self, new_note: NewArticleNote) -> AppResult<ArticleNote>;
+    async fn update_body(&self, id: Uuid, body: String) -> AppResult<ArticleNote>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticlePendingRevision, NewArticlePendingRevision};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait ArticlePendingRevisionRepository: Send + Sync {
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Option<ArticlePendingRevision>>;
+    /// Creates the pending revision for an article, or replaces it if one
+    /// already exists — there is at most one pending revision per article.
+    async fn upsert(&self, revision: NewArticlePendingRevision) -> AppResult<ArticlePendingRevision>;
+    async fn delete(&self, article_id: Uuid) -> AppResult<()>;
+}
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use uuid::Uuid;
+
+use crate::domain::deadline::RequestDeadline;
+use crate::domain::entities::{Article, ArticleLicense, NewArticle};
+use crate::errors::{AppError, AppResult};
+
+#[async_trait]
+pub trait ArticleRepository: Send + Sync {
+    /// All articles, most recent first, optionally narrowed to a single
+    /// license.
+    async fn find_all(&self, license: Option<ArticleLicense>) -> AppResult<Vec<Article>>;
+    /// Same rows as [`Self::find_all`], streamed one row at a time instead
+    /// of materialized into a `Vec`, for callers exporting the full table
+    /// where buffering it all in memory first would be wasteful.
+    fn stream_all(&self, license: Option<ArticleLicense>) -> BoxStream<'static, Result<Article, AppError>>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Article>>;
+    async fn find_by_slug(&self, slug: &str) -> AppResult<Option<Article>>;
+    /// Published articles by any of `author_ids`, most recent first, for a
+    /// personalized feed of followed authors.
+    async fn find_by_authors(&self, author_ids: &[Uuid]) -> AppResult<Vec<Article>>;
+    /// The `limit` most recently published articles, for a not-found page's
+    /// "you might like" fallback when there's no surviving record of the
+    /// article that was actually requested.
+    async fn find_recent_published(&self, limit: i64) -> AppResult<Vec<Article>>;
+    /// A page of articles ordered by id, for batch maintenance jobs that
+    /// need a stable traversal order independent of writes happening
+    /// concurrently.
+    async fn find_page(&self, limit: i64, offset: i64) -> AppResult<Vec<Article>>;
+    async fn create(&self, new_article: NewArticle) -> AppResult<Article>;
+    async fn update(&self, article: Article) -> AppResult<Article>;
+    /// Reassigns the article to a different owner, for handing it off when
+    /// an author leaves a multi-author blog.
+    async fn update_owner(&self, id: Uuid, new_owner_id: Uuid) -> AppResult<Article>;
+    async fn update_derived_fields(
+        &self,
+        id: Uuid,
+        slug: String,
+        word_count: i32,
+        excerpt: String,
+    ) -> AppResult<()>;
+    /// Articles whose title or body is at least `threshold` trigram-similar
+    /// to the given title/body, most similar first, for pre-insert duplicate
+    /// warnings. `deadline` bounds how long the (potentially expensive)
+    /// similarity scan is allowed to run.
+    async fn find_similar(
+        &self,
+        title: &str,
+        body: &str,
+        threshold: f32,
+        limit: i64,
+        deadline: RequestDeadline,
+    ) -> AppResult<Vec<Article>>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+
+    /// The `per_page + 1` most recently published articles whose
+    /// `created_at` falls on this calendar month and day in any year,
+    /// newest first, for an "on this day" archive widget. Optionally
+    /// continues from a prior page's `before` cursor.
+    async fn find_published_by_month_day(
+        &self,
+        month: i32,
+        day: i32,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<Article>>;
+    /// The total number of published articles matching [`Self::find_published_by_month_day`],
+    /// ignoring pagination position so a page's cursor never shifts it.
+    async fn count_published_by_month_day(&self, month: i32, day: i32) -> AppResult<i64>;
+
+    /// The `per_page + 1` most recently published articles whose
+    /// `created_at` falls within this calendar year and month, newest
+    /// first, for date-based archive browsing. Optionally continues from a
+    /// prior page's `before` cursor.
+    async fn find_published_by_year_month(
+        &self,
+        year: i32,
+        month: i32,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<Article>>;
+    /// The total number of published articles matching [`Self::find_published_by_year_month`],
+    /// ignoring pagination position so a page's cursor never shifts it.
+    async fn count_published_by_year_month(&self, year: i32, month: i32) -> AppResult<i64>;
+}
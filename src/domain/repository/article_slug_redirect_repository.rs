@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ArticleSlugRedirect;
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait ArticleSlugRedirectRepository: Send + Sync {
+    /// Idempotent: recording the same old slug for the same article twice
+    /// is a no-op, since a title can be edited back and forth.
+    async fn record(&self, article_id: Uuid, old_slug: &str) -> AppResult<()>;
+    async fn find_by_old_slug(&self, old_slug: &str) -> AppResult<Option<ArticleSlugRedirect>>;
+}
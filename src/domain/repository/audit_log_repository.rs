@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{AuditLog, NewAuditLog};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn record(&self, entry: NewAuditLog) -> AppResult<()>;
+
+    /// The most recent `per_page + 1` entries for `(target_type, target_id)`,
+    /// newest first, optionally continuing from a prior page's `before`
+    /// cursor so callers can detect and fetch the next page.
+    async fn find_by_target(
+        &self,
+        target_type: &str,
+        target_id: Uuid,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<AuditLog>>;
+
+    /// Deletes up to `batch_size` entries older than `cutoff`, returning how
+    /// many were removed so a retention job can page through the table
+    /// without holding a single unbounded transaction.
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>, batch_size: i64) -> AppResult<u64>;
+}
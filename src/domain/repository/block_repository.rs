@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Block, NewBlock};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait BlockRepository: Send + Sync {
+    async fn find_by_author(&self, author_id: Uuid) -> AppResult<Vec<Block>>;
+    async fn create(&self, new_block: NewBlock) -> AppResult<Block>;
+    async fn delete(&self, author_id: Uuid, id: Uuid) -> AppResult<()>;
+    async fn is_blocked(
+        &self,
+        author_id: Uuid,
+        user_id: Option<Uuid>,
+        guest_fingerprint: Option<&str>,
+    ) -> AppResult<bool>;
+}
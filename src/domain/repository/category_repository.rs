@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{Article, Category, Tag};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Category>>;
+
+    /// The number of articles currently assigned to `category_id`.
+    async fn count_articles(&self, category_id: Uuid) -> AppResult<i64>;
+
+    /// The `limit` most recently published articles assigned to
+    /// `category_id`, newest first.
+    async fn find_latest_published_articles(&self, category_id: Uuid, limit: i64) -> AppResult<Vec<Article>>;
+
+    /// The `limit` tags used most often across `category_id`'s articles,
+    /// most-used first.
+    async fn find_top_tags(&self, category_id: Uuid, limit: i64) -> AppResult<Vec<Tag>>;
+    /// Renames the category only if `updated_at` still matches
+    /// `expected_updated_at` (compared to whole-second precision, matching
+    /// `If-Unmodified-Since` semantics). Returns `None` on a stale token.
+    async fn update_name_if_unmodified(
+        &self,
+        id: Uuid,
+        name: String,
+        expected_updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Category>>;
+
+    /// Sets `category_id` to `category_id` on every given article in one
+    /// transaction, overwriting whatever category each one had before.
+    async fn assign_to_articles(&self, category_id: Uuid, article_ids: &[Uuid]) -> AppResult<()>;
+
+    /// Clears `category_id` on every given article currently assigned to
+    /// `category_id`, in one transaction; articles assigned to a different
+    /// category are left as-is.
+    async fn remove_from_articles(&self, category_id: Uuid, article_ids: &[Uuid]) -> AppResult<()>;
+}
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{Comment, CommentModerationStatus, CommentThreadSummary, NewComment};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Comment>>;
+    /// Same rows as [`Self::find_by_article_id`], only those held for
+    /// moderation (`pending` or `spam`), for an admin queue.
+    async fn find_held_for_moderation(&self) -> AppResult<Vec<Comment>>;
+    /// Sets a comment's moderation status directly, for an admin approving
+    /// or rejecting a held comment. Returns `None` if the comment doesn't
+    /// exist.
+    async fn set_moderation_status(
+        &self,
+        id: Uuid,
+        status: CommentModerationStatus,
+    ) -> AppResult<Option<Comment>>;
+    /// Total, top-level, and most-recent-activity counts for the article's
+    /// thread, excluding tombstoned comments.
+    async fn thread_summary(&self, article_id: Uuid) -> AppResult<CommentThreadSummary>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Comment>>;
+    /// Resolves a comment by the integer id it carried under the pre-UUID
+    /// schema, for clients still linking to comments by that id.
+    async fn find_by_legacy_id(&self, legacy_id: i32) -> AppResult<Option<Comment>>;
+    async fn create(&self, new_comment: NewComment) -> AppResult<Comment>;
+    /// Updates `body` only if the row's `updated_at` still matches
+    /// `expected_updated_at` (compared to whole-second precision, matching
+    /// `If-Unmodified-Since` semantics). Returns `None` on a stale token.
+    async fn update_body_if_unmodified(
+        &self,
+        id: Uuid,
+        body: String,
+        expected_updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Comment>>;
+    /// Tombstones a comment in place: replaces its body with a deletion
+    /// marker, clears the author's identity, and stamps `deleted_at`. The
+    /// row (and `parent_id`) is kept so replies don't become orphans.
+    async fn soft_delete(&self, id: Uuid) -> AppResult<()>;
+    /// Scrubs PII from every guest comment matching `guest_fingerprint`,
+    /// replacing the body and clearing the name/fingerprint while leaving
+    /// the row (and thread structure) in place. Returns the rows affected.
+    async fn anonymize_by_guest_fingerprint(&self, guest_fingerprint: &str) -> AppResult<u64>;
+    /// Marks a guest comment as email-verified, but only if `email_hash`
+    /// matches the hash it was submitted with, so a token issued for one
+    /// comment can't verify another. Returns `false` if the comment is
+    /// missing or the hash doesn't match.
+    async fn mark_guest_email_verified(&self, comment_id: Uuid, email_hash: &str) -> AppResult<bool>;
+    /// Clears `ip_hash`/`user_agent` on every comment created before
+    /// `cutoff` that still has one set. Returns the rows affected.
+    async fn scrub_privacy_fields_before(&self, cutoff: DateTime<Utc>) -> AppResult<u64>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::{ContactMessage, NewContactMessage};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait ContactMessageRepository: Send + Sync {
+    async fn create(&self, message: NewContactMessage, is_spam: bool) -> AppResult<ContactMessage>;
+    async fn find_all(&self) -> AppResult<Vec<ContactMessage>>;
+}
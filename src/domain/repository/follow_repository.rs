@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait FollowRepository: Send + Sync {
+    /// Idempotent: following someone twice is a no-op, not a conflict.
+    async fn follow(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<()>;
+    /// Idempotent: unfollowing someone you don't follow is a no-op.
+    async fn unfollow(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<()>;
+    async fn is_following(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<bool>;
+    async fn count_followers(&self, user_id: Uuid) -> AppResult<i64>;
+    async fn count_following(&self, user_id: Uuid) -> AppResult<i64>;
+    /// The ids of every author `user_id` follows, for inclusion in their
+    /// personalized feed.
+    async fn find_followed_ids(&self, user_id: Uuid) -> AppResult<Vec<Uuid>>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Image, ImageListFilter, ImageProcessingStatus};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait ImageRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Image>>;
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Image>>;
+    /// Lists a user's images across all their articles (and the unattached
+    /// library), newest first, matching `filter`.
+    async fn find_by_owner(&self, user_id: Uuid, filter: ImageListFilter) -> AppResult<Vec<Image>>;
+    async fn create(&self, image: Image) -> AppResult<Image>;
+    async fn update_article_id(&self, id: Uuid, article_id: Option<Uuid>) -> AppResult<Image>;
+    async fn update_processing_status(&self, id: Uuid, status: ImageProcessingStatus) -> AppResult<()>;
+    /// Records the alt text the captioning integration suggested for an
+    /// image, once its background suggestion job finishes.
+    async fn update_suggested_alt_text(&self, id: Uuid, suggested_alt_text: String) -> AppResult<()>;
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+    /// Deletes every image attached to `article_id` in one transaction,
+    /// returning the ids that were deleted.
+    async fn delete_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Uuid>>;
+}
@@ -0,0 +1,33 @@
+pub mod analytics_repository;
+pub mod article_lock_repository;
+pub mod article_note_repository;
+pub mod article_pending_revision_repository;
+pub mod article_repository;
+pub mod article_slug_redirect_repository;
+pub mod audit_log_repository;
+pub mod block_repository;
+pub mod category_repository;
+pub mod comment_repository;
+pub mod contact_message_repository;
+pub mod follow_repository;
+pub mod image_repository;
+pub mod sitemap_repository;
+pub mod tag_repository;
+pub mod user_repository;
+
+pub use analytics_repository::AnalyticsRepository;
+pub use article_lock_repository::ArticleLockRepository;
+pub use article_note_repository::ArticleNoteRepository;
+pub use article_pending_revision_repository::ArticlePendingRevisionRepository;
+pub use article_repository::ArticleRepository;
+pub use article_slug_redirect_repository::ArticleSlugRedirectRepository;
+pub use audit_log_repository::AuditLogRepository;
+pub use block_repository::BlockRepository;
+pub use category_repository::CategoryRepository;
+pub use comment_repository::CommentRepository;
+pub use contact_message_repository::ContactMessageRepository;
+pub use follow_repository::FollowRepository;
+pub use image_repository::ImageRepository;
+pub use sitemap_repository::SitemapRepository;
+pub use tag_repository::TagRepository;
+pub use user_repository::UserRepository;
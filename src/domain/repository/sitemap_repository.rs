@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::SitemapEntry;
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait SitemapRepository: Send + Sync {
+    /// Every category with at least one published article, and how many, so
+    /// the sitemap index knows how many paginated files each needs.
+    async fn category_article_counts(&self) -> AppResult<Vec<(Uuid, i64)>>;
+    /// Every tag with at least one published article, and how many.
+    async fn tag_article_counts(&self) -> AppResult<Vec<(Uuid, i64)>>;
+    async fn find_page_by_category(&self, category_id: Uuid, limit: i64, offset: i64) -> AppResult<Vec<SitemapEntry>>;
+    async fn find_page_by_tag(&self, tag_id: Uuid, limit: i64, offset: i64) -> AppResult<Vec<SitemapEntry>>;
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{Article, ArticleStatus, Tag};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Tag>>;
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Tag>>;
+    /// Attaches the tag to every given article in one transaction;
+    /// already-attached pairs are left as-is.
+    async fn attach_to_articles(&self, tag_id: Uuid, article_ids: &[Uuid]) -> AppResult<()>;
+    /// Detaches the tag from every given article in one transaction;
+    /// pairs that weren't attached are left as-is.
+    async fn detach_from_articles(&self, tag_id: Uuid, article_ids: &[Uuid]) -> AppResult<()>;
+
+    /// Makes `tag_ids` the article's complete tag set: inserts whichever are
+    /// missing and deletes whichever are no longer wanted, in one
+    /// transaction. Tags already attached are left untouched so their
+    /// `created_at` survives a repeated save instead of being
+    /// delete-and-reinserted on every edit.
+    async fn sync_article_tags(&self, article_id: Uuid, tag_ids: &[Uuid]) -> AppResult<()>;
+
+    /// The `per_page + 1` most recent articles carrying this tag, newest
+    /// first, optionally continuing from a prior page's `before` cursor.
+    async fn find_articles_page(
+        &self,
+        tag_id: Uuid,
+        status: Option<ArticleStatus>,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<Article>>;
+
+    /// The total number of articles carrying this tag matching `status`,
+    /// ignoring pagination position so a page's cursor never shifts it.
+    async fn count_articles(&self, tag_id: Uuid, status: Option<ArticleStatus>) -> AppResult<i64>;
+}
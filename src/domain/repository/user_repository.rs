@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleStatus, User};
+use crate::errors::AppResult;
+
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<User>>;
+    async fn find_by_email(&self, email: &str) -> AppResult<Option<User>>;
+    async fn update_preferences(
+        &self,
+        id: Uuid,
+        default_article_status: ArticleStatus,
+        default_category_id: Option<Uuid>,
+        timezone: String,
+        locale: String,
+    ) -> AppResult<User>;
+
+    /// Updates the self-service display fields of a user's public profile.
+    async fn update_profile(
+        &self,
+        id: Uuid,
+        bio: Option<String>,
+        website: Option<String>,
+        social_links: Vec<String>,
+    ) -> AppResult<User>;
+
+    /// Admin-only: grants or revokes a user's verification badge.
+    async fn set_verified(&self, id: Uuid, is_verified: bool) -> AppResult<User>;
+}
@@ -0,0 +1,179 @@
+use serde::Serialize;
+
+/// A single rule violation, shaped so a form can show it next to the
+/// offending field. Returned both by the create/update paths (as a 400) and
+/// by the dedicated `/validate` preview endpoints (as a 200 listing every
+/// violation at once, for inline form validation).
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationViolation {
+    pub field: String,
+    pub message: String,
+}
+
+const MAX_ARTICLE_TITLE_LENGTH: usize = 200;
+const MAX_ARTICLE_BODY_LENGTH: usize = 200_000;
+const MAX_COMMENT_BODY_LENGTH: usize = 10_000;
+const MAX_CONTACT_NAME_LENGTH: usize = 200;
+const MAX_CONTACT_MESSAGE_LENGTH: usize = 10_000;
+
+/// Title/body rules shared by article creation, editing, and the
+/// `/articles/validate` preview endpoint.
+pub fn validate_article_fields(title: &str, body: &str) -> Vec<ValidationViolation> {
+    let mut violations = Vec::new();
+
+    let title_len = title.trim().chars().count();
+    if title_len == 0 {
+        violations.push(ValidationViolation {
+            field: "title".to_string(),
+            message: "title must not be empty".to_string(),
+        });
+    } else if title_len > MAX_ARTICLE_TITLE_LENGTH {
+        violations.push(ValidationViolation {
+            field: "title".to_string(),
+            message: format!("title must be at most {MAX_ARTICLE_TITLE_LENGTH} characters"),
+        });
+    }
+
+    let body_len = body.trim().chars().count();
+    if body_len == 0 {
+        violations.push(ValidationViolation {
+            field: "body".to_string(),
+            message: "body must not be empty".to_string(),
+        });
+    } else if body.chars().count() > MAX_ARTICLE_BODY_LENGTH {
+        violations.push(ValidationViolation {
+            field: "body".to_string(),
+            message: format!("body must be at most {MAX_ARTICLE_BODY_LENGTH} characters"),
+        });
+    }
+
+    violations
+}
+
+/// Body rules shared by comment creation, editing, and the
+/// `/comments/validate` preview endpoint.
+pub fn validate_comment_body(body: &str) -> Vec<ValidationViolation> {
+    let mut violations = Vec::new();
+
+    let body_len = body.trim().chars().count();
+    if body_len == 0 {
+        violations.push(ValidationViolation {
+            field: "body".to_string(),
+            message: "body must not be empty".to_string(),
+        });
+    } else if body.chars().count() > MAX_COMMENT_BODY_LENGTH {
+        violations.push(ValidationViolation {
+            field: "body".to_string(),
+            message: format!("body must be at most {MAX_COMMENT_BODY_LENGTH} characters"),
+        });
+    }
+
+    violations
+}
+
+/// Name/email/message rules shared by the site's contact form and its
+/// `/contact/validate` preview endpoint. The email check is deliberately
+/// loose (one `@` with something on both sides) rather than RFC 5322
+/// compliant, matching the level of rigor the rest of this codebase uses
+/// for email addresses it never actually sends to itself.
+pub fn validate_contact_message(name: &str, email: &str, message: &str) -> Vec<ValidationViolation> {
+    let mut violations = Vec::new();
+
+    let name_len = name.trim().chars().count();
+    if name_len == 0 {
+        violations.push(ValidationViolation {
+            field: "name".to_string(),
+            message: "name must not be empty".to_string(),
+        });
+    } else if name_len > MAX_CONTACT_NAME_LENGTH {
+        violations.push(ValidationViolation {
+            field: "name".to_string(),
+            message: format!("name must be at most {MAX_CONTACT_NAME_LENGTH} characters"),
+        });
+    }
+
+    if !is_plausible_email(email) {
+        violations.push(ValidationViolation {
+            field: "email".to_string(),
+            message: "email must be a valid address".to_string(),
+        });
+    }
+
+    let message_len = message.trim().chars().count();
+    if message_len == 0 {
+        violations.push(ValidationViolation {
+            field: "message".to_string(),
+            message: "message must not be empty".to_string(),
+        });
+    } else if message.chars().count() > MAX_CONTACT_MESSAGE_LENGTH {
+        violations.push(ValidationViolation {
+            field: "message".to_string(),
+            message: format!("message must be at most {MAX_CONTACT_MESSAGE_LENGTH} characters"),
+        });
+    }
+
+    violations
+}
+
+fn is_plausible_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => !local.is_empty() && domain.contains('.') && !domain.starts_with('.'),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_an_empty_article_title() {
+        let violations = validate_article_fields("   ", "some body");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "title");
+    }
+
+    #[test]
+    fn rejects_an_oversized_article_title() {
+        let title = "a".repeat(MAX_ARTICLE_TITLE_LENGTH + 1);
+        let violations = validate_article_fields(&title, "some body");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "title");
+    }
+
+    #[test]
+    fn rejects_an_empty_article_body() {
+        let violations = validate_article_fields("a title", "  ");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "body");
+    }
+
+    #[test]
+    fn accepts_a_well_formed_article() {
+        assert!(validate_article_fields("a title", "a body").is_empty());
+    }
+
+    #[test]
+    fn rejects_an_empty_comment_body() {
+        let violations = validate_comment_body("");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "body");
+    }
+
+    #[test]
+    fn accepts_a_well_formed_comment() {
+        assert!(validate_comment_body("looks good").is_empty());
+    }
+
+    #[test]
+    fn rejects_a_malformed_contact_email() {
+        let violations = validate_contact_message("Jane", "not-an-email", "hello");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "email");
+    }
+
+    #[test]
+    fn accepts_a_well_formed_contact_message() {
+        assert!(validate_contact_message("Jane", "jane@example.com", "hello there").is_empty());
+    }
+}
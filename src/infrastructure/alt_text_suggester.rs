@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::errors::{AppError, AppResult};
+
+/// Suggests alt text for an uploaded image via an external captioning API,
+/// abstracted behind a trait the same way [`crate::infrastructure::image_url_provider`]
+/// abstracts image hosting, so a real captioning vendor can be dropped in
+/// (or swapped out) without touching the usecase layer.
+#[async_trait]
+pub trait AltTextSuggester: Send + Sync {
+    /// Returns a suggested alt text for the image at `image_url`, or `None`
+    /// if the captioning service declined to suggest one.
+    async fn suggest(&self, image_url: &str) -> AppResult<Option<String>>;
+}
+
+/// Stand-in used when automatic alt-text suggestion isn't configured:
+/// never suggests anything.
+pub struct NoopAltTextSuggester;
+
+#[async_trait]
+impl AltTextSuggester for NoopAltTextSuggester {
+    async fn suggest(&self, _image_url: &str) -> AppResult<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CaptioningApiResponse {
+    caption: Option<String>,
+}
+
+/// Calls an external captioning API at `api_url` with `{"image_url": ...}`,
+/// authenticated with a bearer `api_key`, expecting a JSON `{"caption": "..."}`
+/// response.
+pub struct HttpAltTextSuggester {
+    api_url: String,
+    api_key: String,
+}
+
+impl HttpAltTextSuggester {
+    pub fn new(api_url: String, api_key: String) -> Self {
+        Self { api_url, api_key }
+    }
+}
+
+#[async_trait]
+impl AltTextSuggester for HttpAltTextSuggester {
+    async fn suggest(&self, image_url: &str) -> AppResult<Option<String>> {
+        let response = reqwest::Client::new()
+            .post(&self.api_url)
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({ "image_url": image_url }))
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("failed to call alt-text captioning API: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::Internal(anyhow::anyhow!(
+                "alt-text captioning API returned {}",
+                response.status()
+            )));
+        }
+
+        let body: CaptioningApiResponse = response
+            .json()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("failed to parse alt-text captioning API response: {e}")))?;
+        Ok(body.caption.filter(|caption| !caption.trim().is_empty()))
+    }
+}
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+
+use crate::errors::{AppError, AppResult};
+
+/// What [`AntispamScorer::score`] scores: the parts of a submission an
+/// Akismet-style comment-check API (or the local heuristic) looks at.
+/// Borrowed rather than owned since every field is already held by the
+/// caller (a [`crate::domain::entities::NewComment`]) at the point this is
+/// called.
+pub struct AntispamInput<'a> {
+    pub body: &'a str,
+    pub author_name: Option<&'a str>,
+    pub author_email: Option<&'a str>,
+    pub ip: Option<&'a str>,
+    pub user_agent: Option<&'a str>,
+}
+
+/// Scores a submission's likelihood of being spam, from `0.0` (certainly
+/// ham) to `1.0` (certainly spam). Abstracted behind a trait the same way
+/// [`crate::infrastructure::alt_text_suggester`] abstracts captioning, so a
+/// real spam-detection vendor can be dropped in (or swapped out) without
+/// touching the usecase layer.
+#[async_trait]
+pub trait AntispamScorer: Send + Sync {
+    async fn score(&self, input: AntispamInput<'_>) -> AppResult<f32>;
+}
+
+/// Signals commonly associated with comment spam: an implausible number of
+/// links, a handful of spam-adjacent phrases, and bodies that are mostly
+/// shouting. Crude compared to a trained classifier, but needs no external
+/// service and never fails, so it doubles as the fallback Akismet degrades
+/// to when the API call itself errors.
+pub struct HeuristicAntispamScorer;
+
+impl HeuristicAntispamScorer {
+    fn heuristic_score(body: &str) -> f32 {
+        let link_count = body.matches("http://").count() + body.matches("https://").count();
+        let mut score: f32 = match link_count {
+            0 => 0.0,
+            1 => 0.1,
+            2 => 0.4,
+            _ => 0.8,
+        };
+
+        const SPAM_PHRASES: &[&str] = &["buy now", "click here", "free money", "work from home", "viagra"];
+        let lowercased = body.to_lowercase();
+        if SPAM_PHRASES.iter().any(|phrase| lowercased.contains(phrase)) {
+            score = score.max(0.9);
+        }
+
+        let letters = body.chars().filter(|c| c.is_alphabetic()).count();
+        let uppercase = body.chars().filter(|c| c.is_uppercase()).count();
+        if letters > 20 && (uppercase as f32 / letters as f32) > 0.7 {
+            score = score.max(0.5);
+        }
+
+        score.clamp(0.0, 1.0)
+    }
+}
+
+#[async_trait]
+impl AntispamScorer for HeuristicAntispamScorer {
+    async fn score(&self, input: AntispamInput<'_>) -> AppResult<f32> {
+        Ok(Self::heuristic_score(input.body))
+    }
+}
+
+/// Calls Akismet's `comment-check` endpoint, falling back to
+/// [`HeuristicAntispamScorer`] (and logging a warning) if the call itself
+/// fails, so an Akismet outage degrades moderation accuracy rather than
+/// blocking comment submission.
+pub struct AkismetAntispamScorer {
+    api_key: String,
+    site_url: String,
+    fallback: HeuristicAntispamScorer,
+}
+
+impl AkismetAntispamScorer {
+    pub fn new(api_key: String, site_url: String) -> Self {
+        Self { api_key, site_url, fallback: HeuristicAntispamScorer }
+    }
+
+    fn endpoint(&self) -> String {
+        format!("https://{}.rest.akismet.com/1.1/comment-check", self.api_key)
+    }
+}
+
+#[async_trait]
+impl AntispamScorer for AkismetAntispamScorer {
+    async fn score(&self, input: AntispamInput<'_>) -> AppResult<f32> {
+        let mut form = vec![
+            ("blog", self.site_url.as_str()),
+            ("comment_type", "comment"),
+            ("comment_content", input.body),
+        ];
+        if let Some(ip) = input.ip {
+            form.push(("user_ip", ip));
+        }
+        if let Some(user_agent) = input.user_agent {
+            form.push(("user_agent", user_agent));
+        }
+        if let Some(author_name) = input.author_name {
+            form.push(("comment_author", author_name));
+        }
+        if let Some(author_email) = input.author_email {
+            form.push(("comment_author_email", author_email));
+        }
+
+        let response = match reqwest::Client::new().post(self.endpoint()).form(&form).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::warn!(error = %e, "akismet comment-check call failed, falling back to heuristic scorer");
+                return self.fallback.score(input).await;
+            }
+        };
+
+        if !response.status().is_success() {
+            tracing::warn!(status = %response.status(), "akismet comment-check returned an error status, falling back to heuristic scorer");
+            return self.fallback.score(input).await;
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("failed to read akismet comment-check response: {e}")))?;
+
+        Ok(if body.trim() == "true" { 1.0 } else { 0.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeuristicAntispamScorer;
+
+    #[test]
+    fn scores_a_plain_comment_as_ham() {
+        assert_eq!(HeuristicAntispamScorer::heuristic_score("nice article, thanks for sharing"), 0.0);
+    }
+
+    #[test]
+    fn scores_multiple_links_as_likely_spam() {
+        assert!(
+            HeuristicAntispamScorer::heuristic_score("check http://a.com and https://b.com and http://c.com")
+                >= 0.8
+        );
+    }
+
+    #[test]
+    fn scores_a_known_spam_phrase_as_spam_regardless_of_case() {
+        assert!(HeuristicAntispamScorer::heuristic_score("CLICK HERE to win a prize") >= 0.9);
+    }
+
+    #[test]
+    fn scores_mostly_shouted_text_as_suspicious() {
+        assert!(HeuristicAntispamScorer::heuristic_score("THIS IS AN AMAZING DEAL YOU MUST SEE RIGHT NOW") >= 0.5);
+    }
+}
@@ -0,0 +1,99 @@
+use std::net::SocketAddr;
+
+use axum::http::HeaderMap;
+
+/// Resolves the address a rate limiter or abuse signal should key on.
+///
+/// `X-Forwarded-For` is attacker-controlled on any request that didn't pass
+/// through a trusted reverse proxy, so it's only consulted when
+/// `trusted_hops` is greater than zero, and then only the entry that many
+/// hops in from the right — the one the nearest trusted proxy actually
+/// appended — is used; everything to its left could have been forged by
+/// the client or an untrusted intermediate hop. With `trusted_hops` at `0`
+/// (no proxy in front of this server), or when the header is absent or too
+/// short, this falls back to the real TCP peer address.
+pub fn resolve_client_ip(headers: &HeaderMap, peer: Option<SocketAddr>, trusted_hops: usize) -> String {
+    if trusted_hops > 0 {
+        if let Some(forwarded_for) = headers.get("x-forwarded-for").and_then(|value| value.to_str().ok()) {
+            let hops: Vec<&str> = forwarded_for.split(',').map(str::trim).filter(|hop| !hop.is_empty()).collect();
+            if let Some(index) = hops.len().checked_sub(trusted_hops) {
+                if let Some(hop) = hops.get(index) {
+                    return hop.to_string();
+                }
+            }
+        }
+    }
+
+    peer.map(|addr| addr.ip().to_string()).unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers_with_xff(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn falls_back_to_the_peer_address_when_no_proxy_is_trusted() {
+        let headers = headers_with_xff("1.2.3.4");
+        let peer: SocketAddr = "10.0.0.1:12345".parse().unwrap();
+
+        assert_eq!(resolve_client_ip(&headers, Some(peer), 0), "10.0.0.1");
+    }
+
+    #[test]
+    fn falls_back_to_the_peer_address_when_the_header_is_missing() {
+        let peer: SocketAddr = "10.0.0.1:12345".parse().unwrap();
+
+        assert_eq!(resolve_client_ip(&HeaderMap::new(), Some(peer), 1), "10.0.0.1");
+    }
+
+    #[test]
+    fn falls_back_to_unknown_when_neither_a_header_nor_a_peer_is_available() {
+        assert_eq!(resolve_client_ip(&HeaderMap::new(), None, 0), "unknown");
+    }
+
+    #[test]
+    fn trusts_only_the_hop_the_nearest_trusted_proxy_appended() {
+        // client, untrusted-hop, trusted-proxy -- only one hop (this
+        // server's own reverse proxy) is trusted, so the rightmost entry
+        // is used and everything to its left is ignored as unverifiable.
+        let headers = headers_with_xff("9.9.9.9, 203.0.113.7, 198.51.100.2");
+        let peer: SocketAddr = "10.0.0.1:12345".parse().unwrap();
+
+        assert_eq!(resolve_client_ip(&headers, Some(peer), 1), "198.51.100.2");
+    }
+
+    #[test]
+    fn trusts_two_hops_back_when_two_proxies_are_trusted() {
+        let headers = headers_with_xff("9.9.9.9, 203.0.113.7, 198.51.100.2");
+        let peer: SocketAddr = "10.0.0.1:12345".parse().unwrap();
+
+        assert_eq!(resolve_client_ip(&headers, Some(peer), 2), "203.0.113.7");
+    }
+
+    #[test]
+    fn a_client_cannot_spoof_its_bucket_by_padding_the_header_with_fake_hops() {
+        // A malicious client can still set X-Forwarded-For on a
+        // direct request, but a single trusted hop always takes the
+        // rightmost entry -- the one the trusted proxy appended -- no
+        // matter how many fake entries the client prepends.
+        let spoofed = headers_with_xff("1.1.1.1, 2.2.2.2, 3.3.3.3, 198.51.100.2");
+        let honest = headers_with_xff("198.51.100.2");
+        let peer: SocketAddr = "10.0.0.1:12345".parse().unwrap();
+
+        assert_eq!(resolve_client_ip(&spoofed, Some(peer), 1), resolve_client_ip(&honest, Some(peer), 1));
+    }
+
+    #[test]
+    fn falls_back_to_the_peer_address_when_the_header_has_fewer_hops_than_trusted() {
+        let headers = headers_with_xff("198.51.100.2");
+        let peer: SocketAddr = "10.0.0.1:12345".parse().unwrap();
+
+        assert_eq!(resolve_client_ip(&headers, Some(peer), 3), "10.0.0.1");
+    }
+}
@@ -0,0 +1,19 @@
+use std::future::Future;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use crate::errors::AppResult;
+
+/// Runs a caller-determined number of independent, fallible operations with
+/// at most `cap` in flight at once, short-circuiting on the first error.
+///
+/// For a small, fixed number of independent calls (two or three), prefer
+/// `tokio::try_join!` directly — it doesn't need a `Vec` of boxed work.
+/// This is for the case where the number of calls varies at runtime, like a
+/// dashboard assembling one query per widget.
+pub async fn join_bounded<F, T>(operations: Vec<F>, cap: usize) -> AppResult<Vec<T>>
+where
+    F: Future<Output = AppResult<T>>,
+{
+    stream::iter(operations).buffer_unordered(cap).try_collect().await
+}
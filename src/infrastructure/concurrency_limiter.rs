@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::timeout;
+
+/// Bounds the number of requests in flight at once. Callers queue for a
+/// permit up to `queue_timeout` and give up once the service is still
+/// saturated when it elapses, so a traffic spike sheds load predictably
+/// instead of letting requests pile up indefinitely against the database
+/// connection pool.
+pub struct ConcurrencyLimiter {
+    semaphore: Semaphore,
+    queue_timeout: Duration,
+}
+
+impl ConcurrencyLimiter {
+    pub fn new(max_in_flight: u32, queue_timeout: Duration) -> Self {
+        Self {
+            semaphore: Semaphore::new(max_in_flight as usize),
+            queue_timeout,
+        }
+    }
+
+    /// Waits up to `queue_timeout` for an in-flight slot, returning `None`
+    /// if the service is still saturated when it elapses.
+    pub async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        timeout(self.queue_timeout, self.semaphore.acquire()).await.ok()?.ok()
+    }
+}
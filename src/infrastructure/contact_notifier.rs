@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::ContactMessage;
+use crate::errors::AppResult;
+
+/// Delivers a non-spam contact form submission to the site admin.
+/// Abstracted behind a trait the same way [`crate::infrastructure::guest_verification`]
+/// abstracts guest email delivery, so a real mail provider can be dropped
+/// in without touching the usecase layer.
+#[async_trait]
+pub trait ContactNotifier: Send + Sync {
+    async fn notify(&self, message: &ContactMessage) -> AppResult<()>;
+}
+
+/// Stand-in notifier used until a real mail provider is wired up: logs the
+/// message it would have sent instead of delivering it.
+pub struct LoggingContactNotifier;
+
+#[async_trait]
+impl ContactNotifier for LoggingContactNotifier {
+    async fn notify(&self, message: &ContactMessage) -> AppResult<()> {
+        tracing::info!(
+            contact_message_id = %message.id,
+            name = %message.name,
+            email = %message.email,
+            "would deliver contact form submission to the site admin"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use regex::Regex;
+
+const EXCERPT_MAX_CHARS: usize = 200;
+
+/// Fields derived from an article's title and body, cheap enough to
+/// recompute on every write but worth storing so list/search queries don't
+/// have to recompute them per row.
+pub struct DerivedArticleFields {
+    pub slug: String,
+    pub word_count: i32,
+    pub excerpt: String,
+}
+
+pub fn derive(title: &str, body: &str) -> DerivedArticleFields {
+    DerivedArticleFields {
+        slug: slugify(title),
+        word_count: body.split_whitespace().count() as i32,
+        excerpt: excerpt_of(body),
+    }
+}
+
+fn slugify(title: &str) -> String {
+    let non_alnum = Regex::new(r"[^a-z0-9]+").unwrap();
+    let slug = non_alnum.replace_all(&title.to_lowercase(), "-").trim_matches('-').to_string();
+    if slug.is_empty() {
+        "untitled".to_string()
+    } else {
+        slug
+    }
+}
+
+fn excerpt_of(body: &str) -> String {
+    let plain = body.trim();
+    if plain.chars().count() <= EXCERPT_MAX_CHARS {
+        return plain.to_string();
+    }
+
+    let truncated: String = plain.chars().take(EXCERPT_MAX_CHARS).collect();
+    match truncated.rsplit_once(char::is_whitespace) {
+        Some((head, _)) => format!("{head}…"),
+        None => format!("{truncated}…"),
+    }
+}
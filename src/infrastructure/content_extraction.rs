@@ -0,0 +1,81 @@
+use regex::Regex;
+
+/// Result of extracting the readable content from a fetched HTML page.
+pub struct ExtractedContent {
+    pub title: String,
+    pub markdown: String,
+    pub image_urls: Vec<String>,
+}
+
+/// Strips boilerplate (script/style/nav/header/footer) and converts the
+/// remaining markup to markdown using a small set of well-known tags.
+///
+/// This is a readability-style heuristic, not a full DOM-based readability
+/// port: it favors the largest block of paragraph text and a handful of
+/// structural tags, which is enough for typical article pages.
+pub fn extract(html: &str) -> ExtractedContent {
+    let title = extract_title(html);
+    let stripped = strip_tags(html, &["script", "style", "nav", "header", "footer", "aside"]);
+    let image_urls = extract_image_urls(&stripped);
+    let markdown = html_to_markdown(&stripped);
+
+    ExtractedContent {
+        title,
+        markdown,
+        image_urls,
+    }
+}
+
+fn extract_title(html: &str) -> String {
+    let re = Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap();
+    re.captures(html)
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().trim().to_string())
+        .unwrap_or_else(|| "Untitled import".to_string())
+}
+
+fn strip_tags(html: &str, tag_names: &[&str]) -> String {
+    let mut result = html.to_string();
+    for tag in tag_names {
+        let re = Regex::new(&format!(r"(?is)<{tag}[^>]*>.*?</{tag}>")).unwrap();
+        result = re.replace_all(&result, "").to_string();
+    }
+    result
+}
+
+fn extract_image_urls(html: &str) -> Vec<String> {
+    let re = Regex::new(r#"(?is)<img[^>]+src=["']([^"']+)["']"#).unwrap();
+    re.captures_iter(html)
+        .map(|c| c[1].to_string())
+        .collect()
+}
+
+fn html_to_markdown(html: &str) -> String {
+    let mut text = html.to_string();
+    let replacements: &[(&str, &str)] = &[
+        (r"(?is)<h1[^>]*>(.*?)</h1>", "\n# $1\n"),
+        (r"(?is)<h2[^>]*>(.*?)</h2>", "\n## $1\n"),
+        (r"(?is)<h3[^>]*>(.*?)</h3>", "\n### $1\n"),
+        (r"(?is)<strong[^>]*>(.*?)</strong>", "**$1**"),
+        (r"(?is)<b[^>]*>(.*?)</b>", "**$1**"),
+        (r"(?is)<em[^>]*>(.*?)</em>", "*$1*"),
+        (r"(?is)<i[^>]*>(.*?)</i>", "*$1*"),
+        (r#"(?is)<a[^>]+href=["']([^"']+)["'][^>]*>(.*?)</a>"#, "[$2]($1)"),
+        (r"(?is)<li[^>]*>(.*?)</li>", "- $1\n"),
+        (r"(?is)<p[^>]*>(.*?)</p>", "\n$1\n"),
+        (r"(?is)<br\s*/?>", "\n"),
+    ];
+
+    for (pattern, replacement) in replacements {
+        let re = Regex::new(pattern).unwrap();
+        text = re.replace_all(&text, *replacement).to_string();
+    }
+
+    let tag_re = Regex::new(r"(?is)<[^>]+>").unwrap();
+    text = tag_re.replace_all(&text, "").to_string();
+
+    let blank_lines_re = Regex::new(r"\n{3,}").unwrap();
+    text = blank_lines_re.replace_all(&text, "\n\n").to_string();
+
+    text.trim().to_string()
+}
@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+/// The strftime pattern used for every supported locale; only the rendered
+/// month/day names and ordering differ, so one pattern per locale is enough
+/// without pulling in a full ICU-style formatting dependency.
+fn pattern_for_locale(locale: &str) -> &'static str {
+    match locale {
+        "ja" => "%Y年%m月%d日 %H:%M",
+        _ => "%b %-d, %Y %H:%M",
+    }
+}
+
+/// Returns `true` if `timezone` is a valid IANA timezone name (e.g.
+/// `"America/New_York"`, `"UTC"`), as accepted by [`format_datetime`].
+pub fn is_valid_timezone(timezone: &str) -> bool {
+    Tz::from_str(timezone).is_ok()
+}
+
+/// Formats a UTC instant in the given IANA timezone and locale, for display
+/// to a single user (frontends, digest/notification emails, exports). This
+/// is the single source of truth those callers should use instead of
+/// formatting `DateTime<Utc>` directly, so that a user's timezone/locale
+/// preference is honored consistently everywhere a timestamp is shown to
+/// them. API responses themselves stay UTC-formatted (RFC 3339).
+///
+/// Returns `None` if `timezone` isn't a recognized IANA name; callers should
+/// validate preferences with [`is_valid_timezone`] before storing them.
+pub fn format_datetime(instant: DateTime<Utc>, timezone: &str, locale: &str) -> Option<String> {
+    let tz = Tz::from_str(timezone).ok()?;
+    Some(instant.with_timezone(&tz).format(pattern_for_locale(locale)).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn formats_in_the_requested_timezone() {
+        let instant = Utc.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap();
+        let formatted = format_datetime(instant, "America/New_York", "en").unwrap();
+        assert_eq!(formatted, "Jan 15, 2024 04:30");
+    }
+
+    #[test]
+    fn formats_with_the_locale_specific_pattern() {
+        let instant = Utc.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap();
+        let formatted = format_datetime(instant, "Asia/Tokyo", "ja").unwrap();
+        assert_eq!(formatted, "2024年01月15日 18:30");
+    }
+
+    #[test]
+    fn rejects_an_unknown_timezone_name() {
+        assert!(!is_valid_timezone("Not/A_Zone"));
+        assert!(format_datetime(Utc::now(), "Not/A_Zone", "en").is_none());
+    }
+}
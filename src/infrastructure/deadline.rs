@@ -0,0 +1,17 @@
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::domain::deadline::RequestDeadline;
+use crate::errors::AppResult;
+
+/// Opens a transaction with `statement_timeout` set to the caller's
+/// remaining request budget, so a query that's already doomed to arrive
+/// too late is canceled server-side instead of occupying a pool connection
+/// until it finishes anyway.
+pub async fn begin_with_deadline(pool: &PgPool, deadline: RequestDeadline) -> AppResult<Transaction<'_, Postgres>> {
+    let mut tx = pool.begin().await?;
+    let millis = deadline.remaining().as_millis();
+    sqlx::query(&format!("SET LOCAL statement_timeout = '{millis}ms'"))
+        .execute(&mut *tx)
+        .await?;
+    Ok(tx)
+}
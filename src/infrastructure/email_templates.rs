@@ -0,0 +1,43 @@
+use askama::Template;
+
+/// Sample verification link shown by the preview endpoint; never an
+/// actual pending comment.
+const SAMPLE_VERIFY_URL: &str =
+    "https://example.com/comments/00000000-0000-0000-0000-000000000000/verify-email?token=sample";
+
+#[derive(Template)]
+#[template(path = "email/guest_verification.html")]
+struct GuestVerificationHtml<'a> {
+    verify_url: &'a str,
+    locale: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "email/guest_verification.txt")]
+struct GuestVerificationText<'a> {
+    verify_url: &'a str,
+    locale: &'a str,
+}
+
+/// The HTML and plain-text parts of a rendered notification email.
+pub struct RenderedEmail {
+    pub html: String,
+    pub text: String,
+}
+
+/// Renders the verification email sent to a guest commenter, in the given
+/// locale (anything other than `"ja"` falls back to English).
+pub fn render_guest_verification(verify_url: &str, locale: &str) -> anyhow::Result<RenderedEmail> {
+    let html = GuestVerificationHtml { verify_url, locale }.render()?;
+    let text = GuestVerificationText { verify_url, locale }.render()?;
+    Ok(RenderedEmail { html, text })
+}
+
+/// Renders a named template with sample data, for the admin preview
+/// endpoint. Returns `None` if `template` isn't a known template name.
+pub fn render_preview(template: &str, locale: &str) -> Option<anyhow::Result<RenderedEmail>> {
+    match template {
+        "guest_verification" => Some(render_guest_verification(SAMPLE_VERIFY_URL, locale)),
+        _ => None,
+    }
+}
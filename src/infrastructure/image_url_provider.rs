@@ -0,0 +1,50 @@
+/// Per-request resizing/format hints passed through to the image proxy.
+/// Ignored by [`LocalImageUrlProvider`].
+#[derive(Debug, Clone, Default)]
+pub struct ImageTransform {
+    pub width: Option<u32>,
+    pub format: Option<String>,
+}
+
+pub trait ImageUrlProvider: Send + Sync {
+    fn resolve(&self, original_url: &str, transform: &ImageTransform) -> String;
+}
+
+/// Fallback used when no CDN/image proxy is configured: serves the stored
+/// URL unchanged.
+pub struct LocalImageUrlProvider;
+
+impl ImageUrlProvider for LocalImageUrlProvider {
+    fn resolve(&self, original_url: &str, _transform: &ImageTransform) -> String {
+        original_url.to_string()
+    }
+}
+
+/// Rewrites to a Cloudflare Images/imgproxy-style proxy URL:
+/// `{base_url}/cdn-cgi/image/{options}/{original_url}`.
+pub struct ProxyImageUrlProvider {
+    base_url: String,
+}
+
+impl ProxyImageUrlProvider {
+    pub fn new(base_url: String) -> Self {
+        Self { base_url }
+    }
+}
+
+impl ImageUrlProvider for ProxyImageUrlProvider {
+    fn resolve(&self, original_url: &str, transform: &ImageTransform) -> String {
+        let mut options = Vec::new();
+        if let Some(width) = transform.width {
+            options.push(format!("width={width}"));
+        }
+        if let Some(format) = &transform.format {
+            options.push(format!("format={format}"));
+        }
+        if options.is_empty() {
+            options.push("format=auto".to_string());
+        }
+
+        format!("{}/cdn-cgi/image/{}/{original_url}", self.base_url, options.join(","))
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A fixed-size reservoir of recent request latencies per route, used to
+/// serve p50/p95/p99 reports without a dedicated metrics stack. Not
+/// distributed: each process tracks its own samples.
+pub struct LatencyRecorder {
+    samples: Mutex<HashMap<String, VecDeque<(Instant, Duration)>>>,
+}
+
+const MAX_SAMPLES_PER_ROUTE: usize = 2000;
+
+pub struct RouteLatencyReport {
+    pub route: String,
+    pub count: usize,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl LatencyRecorder {
+    pub fn new() -> Self {
+        Self {
+            samples: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records a single request's latency for `route`, evicting the oldest
+    /// sample once the per-route reservoir is full.
+    pub fn record(&self, route: &str, duration: Duration) {
+        let mut samples = self.samples.lock().expect("latency recorder mutex poisoned");
+        let entries = samples.entry(route.to_string()).or_default();
+
+        entries.push_back((Instant::now(), duration));
+        if entries.len() > MAX_SAMPLES_PER_ROUTE {
+            entries.pop_front();
+        }
+    }
+
+    /// Returns p50/p95/p99 latency per route, considering only samples taken
+    /// within the last `window`.
+    pub fn report(&self, window: Duration) -> Vec<RouteLatencyReport> {
+        let samples = self.samples.lock().expect("latency recorder mutex poisoned");
+        let now = Instant::now();
+
+        samples
+            .iter()
+            .filter_map(|(route, entries)| {
+                let mut durations: Vec<Duration> = entries
+                    .iter()
+                    .filter(|(recorded_at, _)| now.duration_since(*recorded_at) <= window)
+                    .map(|(_, duration)| *duration)
+                    .collect();
+
+                if durations.is_empty() {
+                    return None;
+                }
+
+                durations.sort();
+                Some(RouteLatencyReport {
+                    route: route.clone(),
+                    count: durations.len(),
+                    p50: percentile(&durations, 0.50),
+                    p95: percentile(&durations, 0.95),
+                    p99: percentile(&durations, 0.99),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for LatencyRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn percentile(sorted_durations: &[Duration], fraction: f64) -> Duration {
+    let index = (((sorted_durations.len() - 1) as f64) * fraction).round() as usize;
+    sorted_durations[index]
+}
@@ -0,0 +1,42 @@
+use std::fs;
+use std::path::Path;
+
+/// Deletes the oldest rotated log files in `directory` whose name starts with
+/// `file_name_prefix`, keeping at most `max_files` of them.
+///
+/// `tracing-appender`'s rolling appender only handles rotation, not
+/// retention, so this runs once at startup to bound disk usage for
+/// deployments that never restart long enough for rotation alone to help.
+pub fn enforce_retention(directory: &str, file_name_prefix: &str, max_files: usize) {
+    let dir = Path::new(directory);
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut log_files: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.starts_with(file_name_prefix))
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().and_then(|m| m.modified()).ok()?;
+            Some((entry.path(), modified))
+        })
+        .collect();
+
+    if log_files.len() <= max_files {
+        return;
+    }
+
+    log_files.sort_by_key(|(_, modified)| *modified);
+
+    let excess = log_files.len() - max_files;
+    for (path, _) in log_files.into_iter().take(excess) {
+        if let Err(err) = fs::remove_file(&path) {
+            tracing::warn!(?path, %err, "failed to prune rotated log file");
+        }
+    }
+}
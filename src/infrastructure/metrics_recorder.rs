@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Counter names app services increment through [`MetricsRecorder`]. Kept
+/// as constants so a typo in one call site doesn't silently open a second,
+/// never-reported counter.
+pub const ARTICLES_PUBLISHED_TOTAL: &str = "articles_published_total";
+pub const COMMENTS_CREATED_TOTAL: &str = "comments_created_total";
+pub const IMAGES_UPLOADED_BYTES: &str = "images_uploaded_bytes";
+/// How often a client still links to a comment by its pre-UUID integer id
+/// (see [`crate::presentation::extractors::CommentIdParam`]), so maintainers
+/// can tell from `GET /admin/performance/metrics` when the compatibility
+/// shim has gone quiet enough to remove.
+pub const DEPRECATED_LEGACY_COMMENT_ID_LOOKUPS_TOTAL: &str = "deprecated_legacy_comment_id_lookups_total";
+
+/// In-process counters for product/business events, complementing
+/// [`crate::infrastructure::latency_recorder::LatencyRecorder`]'s request
+/// latencies with counts dashboards actually care about (articles
+/// published, comments created, image bytes uploaded). Not distributed:
+/// each process tracks its own counts, and they reset on restart.
+pub struct MetricsRecorder {
+    counters: Mutex<HashMap<String, u64>>,
+}
+
+impl MetricsRecorder {
+    pub fn new() -> Self {
+        Self {
+            counters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn increment(&self, name: &str) {
+        self.increment_by(name, 1);
+    }
+
+    pub fn increment_by(&self, name: &str, delta: u64) {
+        let mut counters = self.counters.lock().expect("metrics recorder mutex poisoned");
+        *counters.entry(name.to_string()).or_insert(0) += delta;
+    }
+
+    /// Every counter's current value, sorted by name for a stable report.
+    pub fn snapshot(&self) -> Vec<(String, u64)> {
+        let counters = self.counters.lock().expect("metrics recorder mutex poisoned");
+        let mut entries: Vec<(String, u64)> = counters.iter().map(|(name, count)| (name.clone(), *count)).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+}
+
+impl Default for MetricsRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increment_starts_a_counter_at_one() {
+        let recorder = MetricsRecorder::new();
+        recorder.increment("articles_published_total");
+        assert_eq!(recorder.snapshot(), vec![("articles_published_total".to_string(), 1)]);
+    }
+
+    #[test]
+    fn increment_by_accumulates_across_calls() {
+        let recorder = MetricsRecorder::new();
+        recorder.increment_by("images_uploaded_bytes", 1024);
+        recorder.increment_by("images_uploaded_bytes", 2048);
+        assert_eq!(recorder.snapshot(), vec![("images_uploaded_bytes".to_string(), 3072)]);
+    }
+
+    #[test]
+    fn snapshot_is_sorted_by_counter_name() {
+        let recorder = MetricsRecorder::new();
+        recorder.increment("comments_created_total");
+        recorder.increment("articles_published_total");
+        let names: Vec<String> = recorder.snapshot().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["articles_published_total".to_string(), "comments_created_total".to_string()]);
+    }
+}
@@ -0,0 +1,26 @@
+pub mod alt_text_suggester;
+pub mod antispam_scorer;
+pub mod client_address;
+pub mod concurrency;
+pub mod concurrency_limiter;
+pub mod contact_notifier;
+pub mod content_derivation;
+pub mod content_extraction;
+pub mod datetime_format;
+pub mod db;
+pub mod deadline;
+pub mod email_templates;
+pub mod guest_verification;
+pub mod image_url_provider;
+pub mod jwt;
+pub mod latency_recorder;
+pub mod log_retention;
+pub mod metrics_recorder;
+pub mod object_storage;
+pub mod opengraph;
+pub mod preview_token;
+pub mod rate_limiter;
+pub mod repository_impl;
+pub mod runtime_config;
+pub mod ttl_cache;
+pub mod url_guard;
@@ -0,0 +1,41 @@
+use regex::Regex;
+
+/// OpenGraph/OEmbed metadata scraped from a fetched page's `<head>`, used to
+/// render rich link card previews.
+#[derive(Debug, Clone)]
+pub struct LinkMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// Extracts `og:*` meta tags, falling back to `<title>` when `og:title` is
+/// absent. Like [`content_extraction`](super::content_extraction), this is a
+/// regex-based heuristic rather than a full DOM parser.
+pub fn extract(html: &str) -> LinkMetadata {
+    LinkMetadata {
+        title: meta_property(html, "og:title").or_else(|| title_tag(html)),
+        description: meta_property(html, "og:description"),
+        image_url: meta_property(html, "og:image"),
+    }
+}
+
+fn meta_property(html: &str, property: &str) -> Option<String> {
+    let pattern = format!(
+        r#"(?is)<meta[^>]+property=["']{}["'][^>]+content=["']([^"']*)["']"#,
+        regex::escape(property)
+    );
+    let re = Regex::new(&pattern).ok()?;
+    re.captures(html)
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn title_tag(html: &str) -> Option<String> {
+    let re = Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap();
+    re.captures(html)
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().trim().to_string())
+        .filter(|s| !s.is_empty())
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Long enough for an editor to review a draft through a preview link in
+/// one sitting, short enough that a leaked link doesn't stay usable for long.
+pub const PREVIEW_TOKEN_TTL_MINUTES: i64 = 30;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PreviewTokenClaims {
+    article_id: Uuid,
+    exp: i64,
+}
+
+/// Issues a short-lived token binding its bearer to `article_id`, for an
+/// SSR frontend's draft preview links. Returns the token and when it
+/// expires.
+pub fn issue_preview_token(article_id: Uuid, secret: &str) -> anyhow::Result<(String, DateTime<Utc>)> {
+    let expires_at = Utc::now() + Duration::minutes(PREVIEW_TOKEN_TTL_MINUTES);
+    let claims = PreviewTokenClaims {
+        article_id,
+        exp: expires_at.timestamp(),
+    };
+    let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(secret.as_bytes()))?;
+    Ok((token, expires_at))
+}
+
+/// Decodes a preview token, returning the article it was issued for (its
+/// "audience"), or an error if it's malformed, expired, or tampered with.
+pub fn verify_preview_token(token: &str, secret: &str) -> anyhow::Result<Uuid> {
+    let data = decode::<PreviewTokenClaims>(token, &DecodingKey::from_secret(secret.as_bytes()), &Validation::default())?;
+    Ok(data.claims.article_id)
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A simple fixed-window request counter, keyed by whatever the caller
+/// chooses (typically `client_ip:route`). Not distributed: each process
+/// tracks its own windows.
+pub struct RateLimiter {
+    windows: Mutex<HashMap<String, Window>>,
+}
+
+struct Window {
+    started_at: Instant,
+    count: u32,
+}
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self {
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records a hit for `key` and returns `true` if it is still within
+    /// `limit` requests for the current one-minute window.
+    pub fn check(&self, key: &str, limit: u32) -> bool {
+        let mut windows = self.windows.lock().expect("rate limiter mutex poisoned");
+        let now = Instant::now();
+
+        let window = windows.entry(key.to_string()).or_insert(Window {
+            started_at: now,
+            count: 0,
+        });
+
+        if now.duration_since(window.started_at) >= WINDOW {
+            window.started_at = now;
+            window.count = 0;
+        }
+
+        window.count += 1;
+        window.count <= limit
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
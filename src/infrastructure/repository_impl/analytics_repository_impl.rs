@@ -0,0 +1,101 @@
+use async_stream::try_stream;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use sqlx::PgPool;
+
+use crate::domain::entities::DailyMetrics;
+use crate::domain::repository::AnalyticsRepository;
+use crate::errors::{AppError, AppResult};
+
+const DAILY_METRICS_QUERY: &str = r#"
+WITH days AS (
+    SELECT generate_series($1::date, $2::date, interval '1 day')::date AS day
+),
+views AS (
+    SELECT date_trunc('day', viewed_at)::date AS day, count(*) AS views
+    FROM article_view_events
+    WHERE viewed_at::date BETWEEN $1 AND $2
+    GROUP BY 1
+),
+reactions AS (
+    SELECT date_trunc('day', created_at)::date AS day, count(*) AS reactions
+    FROM reactions
+    WHERE created_at::date BETWEEN $1 AND $2
+    GROUP BY 1
+),
+comments AS (
+    SELECT date_trunc('day', created_at)::date AS day, count(*) AS comments
+    FROM comments
+    WHERE created_at::date BETWEEN $1 AND $2
+    GROUP BY 1
+),
+signups AS (
+    SELECT date_trunc('day', created_at)::date AS day, count(*) AS signups
+    FROM users
+    WHERE created_at::date BETWEEN $1 AND $2
+    GROUP BY 1
+)
+SELECT
+    days.day,
+    coalesce(views.views, 0) AS views,
+    coalesce(reactions.reactions, 0) AS reactions,
+    coalesce(comments.comments, 0) AS comments,
+    coalesce(signups.signups, 0) AS signups
+FROM days
+LEFT JOIN views ON views.day = days.day
+LEFT JOIN reactions ON reactions.day = days.day
+LEFT JOIN comments ON comments.day = days.day
+LEFT JOIN signups ON signups.day = days.day
+ORDER BY days.day
+"#;
+
+pub struct AnalyticsRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AnalyticsRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AnalyticsRepository for AnalyticsRepositoryImpl {
+    fn stream_daily_metrics(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> BoxStream<'static, Result<DailyMetrics, AppError>> {
+        let pool = self.pool.clone();
+
+        try_stream! {
+            let mut rows = sqlx::query_as::<_, DailyMetrics>(DAILY_METRICS_QUERY)
+                .bind(from)
+                .bind(to)
+                .fetch(&pool);
+
+            while let Some(row) = rows.next().await {
+                yield row?;
+            }
+        }
+        .boxed()
+    }
+
+    async fn delete_view_events_older_than(&self, cutoff: DateTime<Utc>, batch_size: i64) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM article_view_events
+            WHERE id IN (
+                SELECT id FROM article_view_events WHERE viewed_at < $1 ORDER BY viewed_at LIMIT $2
+            )
+            "#,
+        )
+        .bind(cutoff)
+        .bind(batch_size)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::ArticleLock;
+use crate::domain::repository::ArticleLockRepository;
+use crate::errors::AppResult;
+
+pub struct ArticleLockRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ArticleLockRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleLockRepository for ArticleLockRepositoryImpl {
+    async fn find_active(&self, article_id: Uuid) -> AppResult<Option<ArticleLock>> {
+        let lock = sqlx::query_as::<_, ArticleLock>(
+            "SELECT * FROM article_locks WHERE article_id = $1 AND expires_at > now()",
+        )
+        .bind(article_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(lock)
+    }
+
+    async fn acquire(&self, article_id: Uuid, owner_id: Uuid, expires_at: DateTime<Utc>) -> AppResult<Option<ArticleLock>> {
+        let lock = sqlx::query_as::<_, ArticleLock>(
+            r#"
+            INSERT INTO article_locks (article_id, owner_id, acquired_at, expires_at)
+            VALUES ($1, $2, now(), $3)
+            ON CONFLICT (article_id) DO UPDATE
+                SET owner_id = excluded.owner_id, acquired_at = now(), expires_at = excluded.expires_at
+                WHERE article_locks.owner_id = excluded.owner_id OR article_locks.expires_at <= now()
+            RETURNING *
+            "#,
+        )
+        .bind(article_id)
+        .bind(owner_id)
+        .bind(expires_at)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(lock)
+    }
+
+    async fn release(&self, article_id: Uuid, owner_id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM article_locks WHERE article_id = $1 AND owner_id = $2")
+            .bind(article_id)
+            .bind(owner_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleNote, NewArticleNote};
+use crate::domain::repository::ArticleNoteRepository;
+use crate::errors::{AppError, AppResult};
+
+pub struct ArticleNoteRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ArticleNoteRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleNoteRepository for ArticleNoteRepositoryImpl {
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<ArticleNote>> {
+        let notes = sqlx::query_as::<_, ArticleNote>(
+            "SELECT * FROM article_notes WHERE article_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(article_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(notes)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<ArticleNote>> {
+        let note = sqlx::query_as::<_, ArticleNote>("SELECT * FROM article_notes WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(note)
+    }
+
+    async fn create(&self, new_note: NewArticleNote) -> AppResult<ArticleNote> {
+        let note = sqlx::query_as::<_, ArticleNote>(
+            r#"
+            INSERT INTO article_notes (article_id, author_id, body)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+        )
+        .bind(new_note.article_id)
+        .bind(new_note.author_id)
+        .bind(new_note.body)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(note)
+    }
+
+    async fn update_body(&self, id: Uuid, body: String) -> AppResult<ArticleNote> {
+        let note = sqlx::query_as::<_, ArticleNote>(
+            "UPDATE article_notes SET body = $2, updated_at = now() WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(body)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("article note {id} not found")))?;
+        Ok(note)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM article_notes WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("article note {id} not found")));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticlePendingRevision, NewArticlePendingRevision};
+use crate::domain::repository::ArticlePendingRevisionRepository;
+use crate::errors::AppResult;
+
+pub struct ArticlePendingRevisionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ArticlePendingRevisionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticlePendingRevisionRepository for ArticlePendingRevisionRepositoryImpl {
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Option<ArticlePendingRevision>> {
+        let revision = sqlx::query_as::<_, ArticlePendingRevision>(
+            "SELECT * FROM article_pending_revisions WHERE article_id = $1",
+        )
+        .bind(article_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(revision)
+    }
+
+    async fn upsert(&self, revision: NewArticlePendingRevision) -> AppResult<ArticlePendingRevision> {
+        let revision = sqlx::query_as::<_, ArticlePendingRevision>(
+            r#"
+            INSERT INTO article_pending_revisions (article_id, title, body, category_id, license, attribution)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (article_id) DO UPDATE
+            SET title = EXCLUDED.title,
+                body = EXCLUDED.body,
+                category_id = EXCLUDED.category_id,
+                license = EXCLUDED.license,
+                attribution = EXCLUDED.attribution,
+                updated_at = now()
+            RETURNING *
+            "#,
+        )
+        .bind(revision.article_id)
+        .bind(revision.title)
+        .bind(revision.body)
+        .bind(revision.category_id)
+        .bind(revision.license)
+        .bind(revision.attribution)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(revision)
+    }
+
+    async fn delete(&self, article_id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM article_pending_revisions WHERE article_id = $1")
+            .bind(article_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+use async_stream::try_stream;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::domain::deadline::RequestDeadline;
+use crate::domain::entities::{Article, ArticleLicense, ArticleStatus, NewArticle};
+use crate::domain::repository::ArticleRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::content_derivation;
+use crate::infrastructure::deadline::begin_with_deadline;
+
+pub struct ArticleRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ArticleRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleRepository for ArticleRepositoryImpl {
+    async fn find_all(&self, license: Option<ArticleLicense>) -> AppResult<Vec<Article>> {
+        let articles = match license {
+            Some(license) => {
+                sqlx::query_as::<_, Article>(
+                    "SELECT * FROM articles WHERE license = $1 ORDER BY created_at DESC",
+                )
+                .bind(license)
+                .fetch_all(&self.pool)
+                .await?
+            }
+            None => {
+                sqlx::query_as::<_, Article>("SELECT * FROM articles ORDER BY created_at DESC")
+                    .fetch_all(&self.pool)
+                    .await?
+            }
+        };
+        Ok(articles)
+    }
+
+    fn stream_all(&self, license: Option<ArticleLicense>) -> BoxStream<'static, Result<Article, AppError>> {
+        let pool = self.pool.clone();
+
+        try_stream! {
+            let mut rows = match license {
+                Some(license) => sqlx::query_as::<_, Article>(
+                    "SELECT * FROM articles WHERE license = $1 ORDER BY created_at DESC",
+                )
+                .bind(license)
+                .fetch(&pool),
+                None => sqlx::query_as::<_, Article>("SELECT * FROM articles ORDER BY created_at DESC").fetch(&pool),
+            };
+
+            while let Some(row) = rows.next().await {
+                yield row?;
+            }
+        }
+        .boxed()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Article>> {
+        let article = sqlx::query_as::<_, Article>("SELECT * FROM articles WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(article)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> AppResult<Option<Article>> {
+        let article = sqlx::query_as::<_, Article>("SELECT * FROM articles WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(article)
+    }
+
+    async fn find_by_authors(&self, author_ids: &[Uuid]) -> AppResult<Vec<Article>> {
+        let articles = sqlx::query_as::<_, Article>(
+            "SELECT * FROM articles WHERE user_id = ANY($1) AND status = $2 ORDER BY created_at DESC",
+        )
+        .bind(author_ids)
+        .bind(ArticleStatus::Published)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(articles)
+    }
+
+    async fn find_recent_published(&self, limit: i64) -> AppResult<Vec<Article>> {
+        let articles = sqlx::query_as::<_, Article>(
+            "SELECT * FROM articles WHERE status = $1 ORDER BY created_at DESC LIMIT $2",
+        )
+        .bind(ArticleStatus::Published)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(articles)
+    }
+
+    async fn find_page(&self, limit: i64, offset: i64) -> AppResult<Vec<Article>> {
+        let articles = sqlx::query_as::<_, Article>("SELECT * FROM articles ORDER BY id LIMIT $1 OFFSET $2")
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(articles)
+    }
+
+    async fn create(&self, new_article: NewArticle) -> AppResult<Article> {
+        let derived = content_derivation::derive(&new_article.title, &new_article.body);
+
+        let article = sqlx::query_as::<_, Article>(
+            r#"
+            INSERT INTO articles (user_id, title, body, status, category_id, license, attribution, slug, word_count, excerpt)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(new_article.user_id)
+        .bind(new_article.title)
+        .bind(new_article.body)
+        .bind(new_article.status.unwrap_or(crate::domain::entities::ArticleStatus::Draft))
+        .bind(new_article.category_id)
+        .bind(new_article.license.unwrap_or(ArticleLicense::AllRightsReserved))
+        .bind(new_article.attribution)
+        .bind(derived.slug)
+        .bind(derived.word_count)
+        .bind(derived.excerpt)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(article)
+    }
+
+    async fn update(&self, article: Article) -> AppResult<Article> {
+        let derived = content_derivation::derive(&article.title, &article.body);
+
+        let updated = sqlx::query_as::<_, Article>(
+            r#"
+            UPDATE articles
+            SET title = $2, body = $3, status = $4, category_id = $5,
+                license = $6, attribution = $7,
+                slug = $8, word_count = $9, excerpt = $10, updated_at = now()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(article.id)
+        .bind(article.title)
+        .bind(article.body)
+        .bind(article.status)
+        .bind(article.category_id)
+        .bind(article.license)
+        .bind(article.attribution)
+        .bind(derived.slug)
+        .bind(derived.word_count)
+        .bind(derived.excerpt)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(updated)
+    }
+
+    async fn update_owner(&self, id: Uuid, new_owner_id: Uuid) -> AppResult<Article> {
+        let article = sqlx::query_as::<_, Article>(
+            "UPDATE articles SET user_id = $2, updated_at = now() WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(new_owner_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("article {id} not found")))?;
+        Ok(article)
+    }
+
+    async fn update_derived_fields(
+        &self,
+        id: Uuid,
+        slug: String,
+        word_count: i32,
+        excerpt: String,
+    ) -> AppResult<()> {
+        sqlx::query("UPDATE articles SET slug = $2, word_count = $3, excerpt = $4 WHERE id = $1")
+            .bind(id)
+            .bind(slug)
+            .bind(word_count)
+            .bind(excerpt)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_similar(
+        &self,
+        title: &str,
+        body: &str,
+        threshold: f32,
+        limit: i64,
+        deadline: RequestDeadline,
+    ) -> AppResult<Vec<Article>> {
+        let mut tx = begin_with_deadline(&self.pool, deadline).await?;
+        let articles = sqlx::query_as::<_, Article>(
+            r#"
+            SELECT * FROM articles
+            WHERE similarity(title, $1) >= $3 OR similarity(body, $2) >= $3
+            ORDER BY greatest(similarity(title, $1), similarity(body, $2)) DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(title)
+        .bind(body)
+        .bind(threshold)
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.rollback().await?;
+        Ok(articles)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM articles WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_published_by_month_day(
+        &self,
+        month: i32,
+        day: i32,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<Article>> {
+        let mut builder = QueryBuilder::new(
+            "SELECT * FROM articles WHERE status = ",
+        );
+        builder.push_bind(ArticleStatus::Published);
+        builder.push(" AND EXTRACT(MONTH FROM created_at)::int = ");
+        builder.push_bind(month);
+        builder.push(" AND EXTRACT(DAY FROM created_at)::int = ");
+        builder.push_bind(day);
+        push_before_cursor(&mut builder, before);
+        builder.push(" ORDER BY created_at DESC LIMIT ");
+        builder.push_bind(per_page + 1);
+
+        let articles = builder.build_query_as::<Article>().fetch_all(&self.pool).await?;
+        Ok(articles)
+    }
+
+    async fn count_published_by_month_day(&self, month: i32, day: i32) -> AppResult<i64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM articles
+             WHERE status = $1 AND EXTRACT(MONTH FROM created_at)::int = $2 AND EXTRACT(DAY FROM created_at)::int = $3",
+        )
+        .bind(ArticleStatus::Published)
+        .bind(month)
+        .bind(day)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+
+    async fn find_published_by_year_month(
+        &self,
+        year: i32,
+        month: i32,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<Article>> {
+        let mut builder = QueryBuilder::new(
+            "SELECT * FROM articles WHERE status = ",
+        );
+        builder.push_bind(ArticleStatus::Published);
+        builder.push(" AND EXTRACT(YEAR FROM created_at)::int = ");
+        builder.push_bind(year);
+        builder.push(" AND EXTRACT(MONTH FROM created_at)::int = ");
+        builder.push_bind(month);
+        push_before_cursor(&mut builder, before);
+        builder.push(" ORDER BY created_at DESC LIMIT ");
+        builder.push_bind(per_page + 1);
+
+        let articles = builder.build_query_as::<Article>().fetch_all(&self.pool).await?;
+        Ok(articles)
+    }
+
+    async fn count_published_by_year_month(&self, year: i32, month: i32) -> AppResult<i64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM articles
+             WHERE status = $1 AND EXTRACT(YEAR FROM created_at)::int = $2 AND EXTRACT(MONTH FROM created_at)::int = $3",
+        )
+        .bind(ArticleStatus::Published)
+        .bind(year)
+        .bind(month)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+}
+
+/// Appends a `created_at < ...` cursor bound to `builder` when `before` is
+/// given, shared by every paginated date-browse query above.
+fn push_before_cursor(builder: &mut QueryBuilder<'_, Postgres>, before: Option<DateTime<Utc>>) {
+    if let Some(before) = before {
+        builder.push(" AND created_at < ");
+        builder.push_bind(before);
+    }
+}
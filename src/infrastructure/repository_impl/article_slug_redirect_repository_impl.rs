@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::ArticleSlugRedirect;
+use crate::domain::repository::ArticleSlugRedirectRepository;
+use crate::errors::AppResult;
+
+pub struct ArticleSlugRedirectRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ArticleSlugRedirectRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleSlugRedirectRepository for ArticleSlugRedirectRepositoryImpl {
+    async fn record(&self, article_id: Uuid, old_slug: &str) -> AppResult<()> {
+        sqlx::query(
+            "INSERT INTO article_slug_redirects (article_id, old_slug) VALUES ($1, $2)
+             ON CONFLICT (article_id, old_slug) DO NOTHING",
+        )
+        .bind(article_id)
+        .bind(old_slug)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn find_by_old_slug(&self, old_slug: &str) -> AppResult<Option<ArticleSlugRedirect>> {
+        let redirect = sqlx::query_as::<_, ArticleSlugRedirect>(
+            "SELECT * FROM article_slug_redirects WHERE old_slug = $1 ORDER BY created_at DESC LIMIT 1",
+        )
+        .bind(old_slug)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(redirect)
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{AuditLog, NewAuditLog};
+use crate::domain::repository::AuditLogRepository;
+use crate::errors::AppResult;
+
+pub struct AuditLogRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AuditLogRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for AuditLogRepositoryImpl {
+    async fn record(&self, entry: NewAuditLog) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO audit_logs (action, target_type, target_id, actor_id, detail)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(entry.action)
+        .bind(entry.target_type)
+        .bind(entry.target_id)
+        .bind(entry.actor_id)
+        .bind(entry.detail)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn find_by_target(
+        &self,
+        target_type: &str,
+        target_id: Uuid,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<AuditLog>> {
+        let rows = sqlx::query_as::<_, AuditLog>(
+            r#"
+            SELECT id, action, target_type, target_id, actor_id, detail, created_at
+            FROM audit_logs
+            WHERE target_type = $1
+              AND target_id = $2
+              AND ($3::timestamptz IS NULL OR created_at < $3)
+            ORDER BY created_at DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(target_type)
+        .bind(target_id)
+        .bind(before)
+        .bind(per_page + 1)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>, batch_size: i64) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM audit_logs
+            WHERE id IN (
+                SELECT id FROM audit_logs WHERE created_at < $1 ORDER BY created_at LIMIT $2
+            )
+            "#,
+        )
+        .bind(cutoff)
+        .bind(batch_size)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{Block, NewBlock};
+use crate::domain::repository::BlockRepository;
+use crate::errors::{AppError, AppResult};
+
+pub struct BlockRepositoryImpl {
+    pool: PgPool,
+}
+
+impl BlockRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BlockRepository for BlockRepositoryImpl {
+    async fn find_by_author(&self, author_id: Uuid) -> AppResult<Vec<Block>> {
+        let blocks = sqlx::query_as::<_, Block>(
+            "SELECT * FROM blocks WHERE author_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(author_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(blocks)
+    }
+
+    async fn create(&self, new_block: NewBlock) -> AppResult<Block> {
+        let block = sqlx::query_as::<_, Block>(
+            r#"
+            INSERT INTO blocks (author_id, blocked_user_id, blocked_guest_fingerprint)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+        )
+        .bind(new_block.author_id)
+        .bind(new_block.blocked_user_id)
+        .bind(new_block.blocked_guest_fingerprint)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(block)
+    }
+
+    async fn delete(&self, author_id: Uuid, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM blocks WHERE id = $1 AND author_id = $2")
+            .bind(id)
+            .bind(author_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("block {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn is_blocked(
+        &self,
+        author_id: Uuid,
+        user_id: Option<Uuid>,
+        guest_fingerprint: Option<&str>,
+    ) -> AppResult<bool> {
+        let exists: (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM blocks
+                WHERE author_id = $1
+                  AND ((blocked_user_id IS NOT NULL AND blocked_user_id = $2)
+                    OR (blocked_guest_fingerprint IS NOT NULL AND blocked_guest_fingerprint = $3))
+            )
+            "#,
+        )
+        .bind(author_id)
+        .bind(user_id)
+        .bind(guest_fingerprint)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists.0)
+    }
+}
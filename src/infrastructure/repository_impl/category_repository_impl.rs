@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{Article, ArticleStatus, Category, Tag};
+use crate::domain::repository::CategoryRepository;
+use crate::errors::AppResult;
+
+pub struct CategoryRepositoryImpl {
+    pool: PgPool,
+}
+
+impl CategoryRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for CategoryRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Category>> {
+        let category = sqlx::query_as::<_, Category>("SELECT * FROM categories WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(category)
+    }
+
+    async fn count_articles(&self, category_id: Uuid) -> AppResult<i64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM articles WHERE category_id = $1")
+            .bind(category_id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count)
+    }
+
+    async fn find_latest_published_articles(&self, category_id: Uuid, limit: i64) -> AppResult<Vec<Article>> {
+        let articles = sqlx::query_as::<_, Article>(
+            "SELECT * FROM articles WHERE category_id = $1 AND status = $2 ORDER BY created_at DESC LIMIT $3",
+        )
+        .bind(category_id)
+        .bind(ArticleStatus::Published)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(articles)
+    }
+
+    async fn find_top_tags(&self, category_id: Uuid, limit: i64) -> AppResult<Vec<Tag>> {
+        let tags = sqlx::query_as::<_, Tag>(
+            r#"
+            SELECT tags.* FROM tags
+            INNER JOIN article_tags ON article_tags.tag_id = tags.id
+            INNER JOIN articles ON articles.id = article_tags.article_id
+            WHERE articles.category_id = $1
+            GROUP BY tags.id
+            ORDER BY COUNT(*) DESC, tags.name ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(category_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(tags)
+    }
+
+    async fn update_name_if_unmodified(
+        &self,
+        id: Uuid,
+        name: String,
+        expected_updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Category>> {
+        let category = sqlx::query_as::<_, Category>(
+            r#"
+            UPDATE categories
+            SET name = $2, updated_at = now()
+            WHERE id = $1 AND date_trunc('second', updated_at) = date_trunc('second', $3::timestamptz)
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(name)
+        .bind(expected_updated_at)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(category)
+    }
+
+    async fn assign_to_articles(&self, category_id: Uuid, article_ids: &[Uuid]) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+        for article_id in article_ids {
+            sqlx::query("UPDATE articles SET category_id = $2, updated_at = now() WHERE id = $1")
+                .bind(article_id)
+                .bind(category_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn remove_from_articles(&self, category_id: Uuid, article_ids: &[Uuid]) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+        for article_id in article_ids {
+            sqlx::query(
+                "UPDATE articles SET category_id = NULL, updated_at = now() WHERE id = $1 AND category_id = $2",
+            )
+            .bind(article_id)
+            .bind(category_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{Comment, CommentModerationStatus, CommentThreadSummary, NewComment};
+use crate::domain::repository::CommentRepository;
+use crate::errors::AppResult;
+
+const TOMBSTONE_BODY: &str = "[deleted]";
+
+pub struct CommentRepositoryImpl {
+    pool: PgPool,
+}
+
+impl CommentRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CommentRepository for CommentRepositoryImpl {
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Comment>> {
+        let comments = sqlx::query_as::<_, Comment>(
+            "SELECT * FROM comments WHERE article_id = $1 AND moderation_status = 'visible' ORDER BY created_at ASC",
+        )
+        .bind(article_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(comments)
+    }
+
+    async fn find_held_for_moderation(&self) -> AppResult<Vec<Comment>> {
+        let comments = sqlx::query_as::<_, Comment>(
+            "SELECT * FROM comments WHERE moderation_status != 'visible' ORDER BY created_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(comments)
+    }
+
+    async fn set_moderation_status(
+        &self,
+        id: Uuid,
+        status: CommentModerationStatus,
+    ) -> AppResult<Option<Comment>> {
+        let comment = sqlx::query_as::<_, Comment>(
+            "UPDATE comments SET moderation_status = $2, updated_at = now() WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(status)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(comment)
+    }
+
+    async fn thread_summary(&self, article_id: Uuid) -> AppResult<CommentThreadSummary> {
+        let summary = sqlx::query_as::<_, CommentThreadSummary>(
+            r#"
+            SELECT
+                count(*) FILTER (WHERE deleted_at IS NULL) AS total,
+                count(*) FILTER (WHERE deleted_at IS NULL AND parent_id IS NULL) AS top_level_count,
+                max(created_at) FILTER (WHERE deleted_at IS NULL) AS latest_comment_at
+            FROM comments
+            WHERE article_id = $1
+            "#,
+        )
+        .bind(article_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(summary)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Comment>> {
+        let comment = sqlx::query_as::<_, Comment>("SELECT * FROM comments WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(comment)
+    }
+
+    async fn find_by_legacy_id(&self, legacy_id: i32) -> AppResult<Option<Comment>> {
+        let comment = sqlx::query_as::<_, Comment>("SELECT * FROM comments WHERE legacy_id = $1")
+            .bind(legacy_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(comment)
+    }
+
+    async fn create(&self, new_comment: NewComment) -> AppResult<Comment> {
+        let comment = sqlx::query_as::<_, Comment>(
+            r#"
+            INSERT INTO comments (
+                article_id, parent_id, user_id, guest_name, guest_fingerprint, guest_email_hash, body,
+                ip_hash, user_agent, moderation_status
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(new_comment.article_id)
+        .bind(new_comment.parent_id)
+        .bind(new_comment.user_id)
+        .bind(new_comment.guest_name)
+        .bind(new_comment.guest_fingerprint)
+        .bind(new_comment.guest_email_hash)
+        .bind(new_comment.body)
+        .bind(new_comment.ip_hash)
+        .bind(new_comment.user_agent)
+        .bind(new_comment.moderation_status.unwrap_or(CommentModerationStatus::Visible))
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(comment)
+    }
+
+    async fn update_body_if_unmodified(
+        &self,
+        id: Uuid,
+        body: String,
+        expected_updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Comment>> {
+        let comment = sqlx::query_as::<_, Comment>(
+            r#"
+            UPDATE comments
+            SET body = $2, updated_at = now()
+            WHERE id = $1 AND date_trunc('second', updated_at) = date_trunc('second', $3::timestamptz)
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(body)
+        .bind(expected_updated_at)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(comment)
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE comments
+            SET body = $2, user_id = NULL, guest_name = NULL, guest_fingerprint = NULL,
+                guest_email_hash = NULL, guest_email_verified_at = NULL,
+                deleted_at = now(), updated_at = now()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(TOMBSTONE_BODY)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn anonymize_by_guest_fingerprint(&self, guest_fingerprint: &str) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE comments
+            SET body = $2, guest_name = NULL, guest_fingerprint = NULL,
+                guest_email_hash = NULL, guest_email_verified_at = NULL,
+                ip_hash = NULL, user_agent = NULL, updated_at = now()
+            WHERE guest_fingerprint = $1
+            "#,
+        )
+        .bind(guest_fingerprint)
+        .bind(TOMBSTONE_BODY)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn mark_guest_email_verified(&self, comment_id: Uuid, email_hash: &str) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE comments
+            SET guest_email_verified_at = now(), updated_at = now()
+            WHERE id = $1 AND guest_email_hash = $2
+            "#,
+        )
+        .bind(comment_id)
+        .bind(email_hash)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn scrub_privacy_fields_before(&self, cutoff: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE comments
+            SET ip_hash = NULL, user_agent = NULL
+            WHERE created_at < $1 AND (ip_hash IS NOT NULL OR user_agent IS NOT NULL)
+            "#,
+        )
+        .bind(cutoff)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::{ContactMessage, NewContactMessage};
+use crate::domain::repository::ContactMessageRepository;
+use crate::errors::AppResult;
+
+pub struct ContactMessageRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ContactMessageRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ContactMessageRepository for ContactMessageRepositoryImpl {
+    async fn create(&self, message: NewContactMessage, is_spam: bool) -> AppResult<ContactMessage> {
+        let message = sqlx::query_as::<_, ContactMessage>(
+            r#"
+            INSERT INTO contact_messages (name, email, message, is_spam)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(message.name)
+        .bind(message.email)
+        .bind(message.message)
+        .bind(is_spam)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(message)
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<ContactMessage>> {
+        let messages =
+            sqlx::query_as::<_, ContactMessage>("SELECT * FROM contact_messages ORDER BY created_at DESC")
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(messages)
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repository::FollowRepository;
+use crate::errors::AppResult;
+
+pub struct FollowRepositoryImpl {
+    pool: PgPool,
+}
+
+impl FollowRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FollowRepository for FollowRepositoryImpl {
+    async fn follow(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<()> {
+        sqlx::query(
+            "INSERT INTO follows (follower_id, followed_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        )
+        .bind(follower_id)
+        .bind(followed_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn unfollow(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM follows WHERE follower_id = $1 AND followed_id = $2")
+            .bind(follower_id)
+            .bind(followed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn is_following(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<bool> {
+        let exists: (bool,) = sqlx::query_as(
+            "SELECT EXISTS (SELECT 1 FROM follows WHERE follower_id = $1 AND followed_id = $2)",
+        )
+        .bind(follower_id)
+        .bind(followed_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(exists.0)
+    }
+
+    async fn count_followers(&self, user_id: Uuid) -> AppResult<i64> {
+        let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM follows WHERE followed_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count.0)
+    }
+
+    async fn count_following(&self, user_id: Uuid) -> AppResult<i64> {
+        let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM follows WHERE follower_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count.0)
+    }
+
+    async fn find_followed_ids(&self, user_id: Uuid) -> AppResult<Vec<Uuid>> {
+        let rows: Vec<(Uuid,)> = sqlx::query_as("SELECT followed_id FROM follows WHERE follower_id = $1")
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+}
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, QueryBuilder};
+use uuid::Uuid;
+
+use crate::domain::entities::{Image, ImageListFilter, ImageProcessingStatus};
+use crate::domain::repository::ImageRepository;
+use crate::errors::AppResult;
+
+pub struct ImageRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ImageRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImageRepository for ImageRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Image>> {
+        let image = sqlx::query_as::<_, Image>("SELECT * FROM images WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(image)
+    }
+
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Image>> {
+        let images = sqlx::query_as::<_, Image>(
+            "SELECT * FROM images WHERE article_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(article_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(images)
+    }
+
+    async fn find_by_owner(&self, user_id: Uuid, filter: ImageListFilter) -> AppResult<Vec<Image>> {
+        let mut query = QueryBuilder::new("SELECT * FROM images WHERE user_id = ");
+        query.push_bind(user_id);
+
+        match filter.attached {
+            Some(true) => {
+                query.push(" AND article_id IS NOT NULL");
+            }
+            Some(false) => {
+                query.push(" AND article_id IS NULL");
+            }
+            None => {}
+        }
+
+        if let Some(mime_type) = &filter.mime_type {
+            query.push(" AND mime_type = ");
+            query.push_bind(mime_type);
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND created_at >= ");
+            query.push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND created_at <= ");
+            query.push_bind(to);
+        }
+
+        query.push(" ORDER BY created_at DESC LIMIT ");
+        query.push_bind(filter.limit);
+        query.push(" OFFSET ");
+        query.push_bind(filter.offset);
+
+        let images = query.build_query_as::<Image>().fetch_all(&self.pool).await?;
+        Ok(images)
+    }
+
+    async fn create(&self, image: Image) -> AppResult<Image> {
+        let created = sqlx::query_as::<_, Image>(
+            r#"
+            INSERT INTO images (id, article_id, user_id, url, mime_type, processing_status)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(image.id)
+        .bind(image.article_id)
+        .bind(image.user_id)
+        .bind(image.url)
+        .bind(image.mime_type)
+        .bind(image.processing_status)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(created)
+    }
+
+    async fn update_article_id(&self, id: Uuid, article_id: Option<Uuid>) -> AppResult<Image> {
+        let image = sqlx::query_as::<_, Image>(
+            r#"
+            UPDATE images
+            SET article_id = $2, updated_at = now()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(article_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(image)
+    }
+
+    async fn update_processing_status(&self, id: Uuid, status: ImageProcessingStatus) -> AppResult<()> {
+        sqlx::query("UPDATE images SET processing_status = $2, updated_at = now() WHERE id = $1")
+            .bind(id)
+            .bind(status)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_suggested_alt_text(&self, id: Uuid, suggested_alt_text: String) -> AppResult<()> {
+        sqlx::query("UPDATE images SET suggested_alt_text = $2, updated_at = now() WHERE id = $1")
+            .bind(id)
+            .bind(suggested_alt_text)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM images WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+        let rows: Vec<(Uuid,)> = sqlx::query_as("DELETE FROM images WHERE article_id = $1 RETURNING id")
+            .bind(article_id)
+            .fetch_all(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+}
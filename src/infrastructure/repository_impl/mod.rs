@@ -0,0 +1,33 @@
+pub mod analytics_repository_impl;
+pub mod article_lock_repository_impl;
+pub mod article_note_repository_impl;
+pub mod article_pending_revision_repository_impl;
+pub mod article_repository_impl;
+pub mod article_slug_redirect_repository_impl;
+pub mod audit_log_repository_impl;
+pub mod block_repository_impl;
+pub mod category_repository_impl;
+pub mod comment_repository_impl;
+pub mod contact_message_repository_impl;
+pub mod follow_repository_impl;
+pub mod image_repository_impl;
+pub mod sitemap_repository_impl;
+pub mod tag_repository_impl;
+pub mod user_repository_impl;
+
+pub use analytics_repository_impl::AnalyticsRepositoryImpl;
+pub use article_lock_repository_impl::ArticleLockRepositoryImpl;
+pub use article_note_repository_impl::ArticleNoteRepositoryImpl;
+pub use article_pending_revision_repository_impl::ArticlePendingRevisionRepositoryImpl;
+pub use article_repository_impl::ArticleRepositoryImpl;
+pub use article_slug_redirect_repository_impl::ArticleSlugRedirectRepositoryImpl;
+pub use audit_log_repository_impl::AuditLogRepositoryImpl;
+pub use block_repository_impl::BlockRepositoryImpl;
+pub use category_repository_impl::CategoryRepositoryImpl;
+pub use comment_repository_impl::CommentRepositoryImpl;
+pub use contact_message_repository_impl::ContactMessageRepositoryImpl;
+pub use follow_repository_impl::FollowRepositoryImpl;
+pub use image_repository_impl::ImageRepositoryImpl;
+pub use sitemap_repository_impl::SitemapRepositoryImpl;
+pub use tag_repository_impl::TagRepositoryImpl;
+pub use user_repository_impl::UserRepositoryImpl;
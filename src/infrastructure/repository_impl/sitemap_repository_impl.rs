@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleStatus, SitemapEntry};
+use crate::domain::repository::SitemapRepository;
+use crate::errors::AppResult;
+
+pub struct SitemapRepositoryImpl {
+    pool: PgPool,
+}
+
+impl SitemapRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SitemapRepository for SitemapRepositoryImpl {
+    async fn category_article_counts(&self) -> AppResult<Vec<(Uuid, i64)>> {
+        let counts = sqlx::query_as::<_, (Uuid, i64)>(
+            r#"
+            SELECT category_id, count(*)
+            FROM articles
+            WHERE status = $1 AND category_id IS NOT NULL
+            GROUP BY category_id
+            "#,
+        )
+        .bind(ArticleStatus::Published)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(counts)
+    }
+
+    async fn tag_article_counts(&self) -> AppResult<Vec<(Uuid, i64)>> {
+        let counts = sqlx::query_as::<_, (Uuid, i64)>(
+            r#"
+            SELECT at.tag_id, count(*)
+            FROM article_tags at
+            JOIN articles a ON a.id = at.article_id
+            WHERE a.status = $1
+            GROUP BY at.tag_id
+            "#,
+        )
+        .bind(ArticleStatus::Published)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(counts)
+    }
+
+    async fn find_page_by_category(&self, category_id: Uuid, limit: i64, offset: i64) -> AppResult<Vec<SitemapEntry>> {
+        let entries = sqlx::query_as::<_, SitemapEntry>(
+            r#"
+            SELECT id AS article_id, slug, updated_at
+            FROM articles
+            WHERE status = $1 AND category_id = $2
+            ORDER BY id
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(ArticleStatus::Published)
+        .bind(category_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(entries)
+    }
+
+    async fn find_page_by_tag(&self, tag_id: Uuid, limit: i64, offset: i64) -> AppResult<Vec<SitemapEntry>> {
+        let entries = sqlx::query_as::<_, SitemapEntry>(
+            r#"
+            SELECT a.id AS article_id, a.slug, a.updated_at
+            FROM articles a
+            JOIN article_tags at ON at.article_id = a.id
+            WHERE a.status = $1 AND at.tag_id = $2
+            ORDER BY a.id
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(ArticleStatus::Published)
+        .bind(tag_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(entries)
+    }
+}
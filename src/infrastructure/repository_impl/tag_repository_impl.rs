@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::domain::entities::{Article, ArticleStatus, Tag};
+use crate::domain::repository::TagRepository;
+use crate::errors::AppResult;
+
+pub struct TagRepositoryImpl {
+    pool: PgPool,
+}
+
+/// Appends the `FROM ... WHERE ...` shared by [`TagRepositoryImpl::find_articles_page`]
+/// and [`TagRepositoryImpl::count_articles`] to `builder`, so the two
+/// statements can never drift out of sync on which articles they consider
+/// a match for `tag_id`/`status`.
+fn push_articles_by_tag_filter(builder: &mut QueryBuilder<'_, Postgres>, tag_id: Uuid, status: Option<ArticleStatus>) {
+    builder.push(" FROM articles a INNER JOIN article_tags at ON at.article_id = a.id WHERE at.tag_id = ");
+    builder.push_bind(tag_id);
+
+    if let Some(status) = status {
+        builder.push(" AND a.status = ");
+        builder.push_bind(status);
+    }
+}
+
+impl TagRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Tag>> {
+        let tag = sqlx::query_as::<_, Tag>("SELECT * FROM tags WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(tag)
+    }
+
+    async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Tag>> {
+        let tags = sqlx::query_as::<_, Tag>(
+            r#"
+            SELECT tags.* FROM tags
+            INNER JOIN article_tags ON article_tags.tag_id = tags.id
+            WHERE article_tags.article_id = $1
+            ORDER BY tags.name ASC
+            "#,
+        )
+        .bind(article_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(tags)
+    }
+
+    async fn attach_to_articles(&self, tag_id: Uuid, article_ids: &[Uuid]) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+        for article_id in article_ids {
+            sqlx::query(
+                "INSERT INTO article_tags (article_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            )
+            .bind(article_id)
+            .bind(tag_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn detach_from_articles(&self, tag_id: Uuid, article_ids: &[Uuid]) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+        for article_id in article_ids {
+            sqlx::query("DELETE FROM article_tags WHERE article_id = $1 AND tag_id = $2")
+                .bind(article_id)
+                .bind(tag_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn sync_article_tags(&self, article_id: Uuid, tag_ids: &[Uuid]) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for tag_id in tag_ids {
+            sqlx::query("INSERT INTO article_tags (article_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+                .bind(article_id)
+                .bind(tag_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        sqlx::query("DELETE FROM article_tags WHERE article_id = $1 AND NOT (tag_id = ANY($2))")
+            .bind(article_id)
+            .bind(tag_ids)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_articles_page(
+        &self,
+        tag_id: Uuid,
+        status: Option<ArticleStatus>,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<Article>> {
+        let mut builder = QueryBuilder::new("SELECT a.*");
+        push_articles_by_tag_filter(&mut builder, tag_id, status);
+
+        if let Some(before) = before {
+            builder.push(" AND a.created_at < ");
+            builder.push_bind(before);
+        }
+
+        builder.push(" ORDER BY a.created_at DESC LIMIT ");
+        builder.push_bind(per_page + 1);
+
+        let articles = builder.build_query_as::<Article>().fetch_all(&self.pool).await?;
+        Ok(articles)
+    }
+
+    async fn count_articles(&self, tag_id: Uuid, status: Option<ArticleStatus>) -> AppResult<i64> {
+        let mut builder = QueryBuilder::new("SELECT COUNT(*)");
+        push_articles_by_tag_filter(&mut builder, tag_id, status);
+
+        let count: i64 = builder.build_query_scalar().fetch_one(&self.pool).await?;
+        Ok(count)
+    }
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleStatus, User};
+use crate::domain::repository::UserRepository;
+use crate::errors::AppResult;
+
+pub struct UserRepositoryImpl {
+    pool: PgPool,
+}
+
+impl UserRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepository for UserRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(user)
+    }
+
+    async fn find_by_email(&self, email: &str) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE email = $1")
+            .bind(email)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(user)
+    }
+
+    async fn update_preferences(
+        &self,
+        id: Uuid,
+        default_article_status: ArticleStatus,
+        default_category_id: Option<Uuid>,
+        timezone: String,
+        locale: String,
+    ) -> AppResult<User> {
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users
+            SET default_article_status = $2, default_category_id = $3, timezone = $4, locale = $5, updated_at = now()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(default_article_status)
+        .bind(default_category_id)
+        .bind(timezone)
+        .bind(locale)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(user)
+    }
+
+    async fn update_profile(
+        &self,
+        id: Uuid,
+        bio: Option<String>,
+        website: Option<String>,
+        social_links: Vec<String>,
+    ) -> AppResult<User> {
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users
+            SET bio = $2, website = $3, social_links = $4, updated_at = now()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(bio)
+        .bind(website)
+        .bind(social_links)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(user)
+    }
+
+    async fn set_verified(&self, id: Uuid, is_verified: bool) -> AppResult<User> {
+        let user = sqlx::query_as::<_, User>(
+            "UPDATE users SET is_verified = $2, updated_at = now() WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(is_verified)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(user)
+    }
+}
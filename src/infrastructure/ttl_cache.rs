@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A simple in-process TTL cache keyed by string. Not distributed: each
+/// process holds its own entries, matching the style of [`RateLimiter`]
+/// (crate::infrastructure::rate_limiter::RateLimiter).
+pub struct TtlCache<V: Clone> {
+    entries: Mutex<HashMap<String, (V, Instant)>>,
+    ttl: Duration,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<V> {
+        let mut entries = self.entries.lock().expect("ttl cache mutex poisoned");
+        match entries.get(key) {
+            Some((value, stored_at)) if stored_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: String, value: V) {
+        let mut entries = self.entries.lock().expect("ttl cache mutex poisoned");
+        entries.insert(key, (value, Instant::now()));
+    }
+}
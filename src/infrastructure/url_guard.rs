@@ -0,0 +1,265 @@
+use std::net::{IpAddr, SocketAddr};
+
+use futures::StreamExt;
+use reqwest::{Client, Response};
+use url::{Host, Url};
+
+use crate::errors::{AppError, AppResult};
+
+/// Schemes this server will make outbound requests over.
+const ALLOWED_SCHEMES: &[&str] = &["http", "https"];
+/// Ports this server will make outbound requests to, so a crafted
+/// unfurl/import/webhook URL can't be used to probe internal services
+/// (databases, caches, admin panels) listening on other ports.
+const ALLOWED_PORTS: &[u16] = &[80, 443];
+/// Redirects [`fetch_guarded`] follows before giving up, so a redirect
+/// chain can't be used to exhaust resources or eventually land on an
+/// internal address without every hop being checked.
+const MAX_REDIRECTS: u8 = 5;
+/// Response bodies [`fetch_guarded`] reads are capped at this size, so a
+/// slow or malicious server can't hold an unbounded allocation open.
+const MAX_RESPONSE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Rejects URLs that could be used to make the server issue requests against
+/// internal infrastructure (disallowed scheme/port, or a literal IP host in
+/// a loopback, link-local or private range).
+///
+/// This only inspects literal IP hosts; a hostname that resolves to an
+/// internal address still passes here. Callers making an actual outbound
+/// request should use [`check_url`] (or [`fetch_guarded`]) instead, which
+/// also resolves the hostname.
+pub fn is_publicly_routable(raw_url: &str) -> bool {
+    let Ok(url) = Url::parse(raw_url) else {
+        return false;
+    };
+
+    if !is_allowed_scheme_and_port(&url) {
+        return false;
+    }
+
+    // `url.host_str()` returns the bracketed form of an IPv6 literal (e.g.
+    // `"[::1]"`), which doesn't parse as an `IpAddr`; `url.host()` instead
+    // hands back a typed `Host::Ipv4`/`Host::Ipv6` with no re-parsing needed.
+    match url.host() {
+        Some(Host::Ipv4(v4)) => is_public_ip(IpAddr::V4(v4)),
+        Some(Host::Ipv6(v6)) => is_public_ip(IpAddr::V6(v6)),
+        Some(Host::Domain(_)) => true,
+        None => false,
+    }
+}
+
+fn is_allowed_scheme_and_port(url: &Url) -> bool {
+    if !ALLOWED_SCHEMES.contains(&url.scheme()) {
+        return false;
+    }
+    url.port_or_known_default().is_some_and(|port| ALLOWED_PORTS.contains(&port))
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_ipv4(v4),
+        // An IPv4-mapped address (`::ffff:a.b.c.d`) is the same address the
+        // v4 rules above already cover, just spelled as v6; unwrap it and
+        // apply those rules instead of letting it skip them entirely.
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_public_ipv4(v4),
+            None => {
+                !v6.is_loopback() && !v6.is_unspecified() && !v6.is_unique_local() && !v6.is_unicast_link_local()
+            }
+        },
+    }
+}
+
+fn is_public_ipv4(v4: std::net::Ipv4Addr) -> bool {
+    !v4.is_loopback() && !v4.is_private() && !v4.is_link_local() && !v4.is_unspecified() && !v4.is_broadcast()
+}
+
+/// Like [`is_publicly_routable`], but resolves the host through DNS and
+/// rejects the URL unless every resolved address is publicly routable, so a
+/// hostname that only *looks* external (DNS rebinding, a internal-only
+/// record) can't slip through. Returns the parsed URL together with the
+/// exact addresses that were checked, so a caller can pin its actual
+/// request to them instead of letting the http client re-resolve the host
+/// and possibly land somewhere that was never validated.
+pub async fn check_url(raw_url: &str) -> AppResult<(Url, Vec<IpAddr>)> {
+    let url = Url::parse(raw_url).map_err(|_| AppError::BadRequest("invalid url".to_string()))?;
+
+    if !is_allowed_scheme_and_port(&url) {
+        return Err(AppError::BadRequest(
+            "url must use http or https on a standard port".to_string(),
+        ));
+    }
+
+    // Match on the typed host rather than re-parsing `host_str()`: for an
+    // IPv6 literal that string is bracketed (e.g. `"[::1]"`), which fails to
+    // parse as an `IpAddr` and would otherwise fall through to a DNS lookup
+    // that can only fail, rejecting every IPv6-literal URL outright.
+    let addrs: Vec<IpAddr> = match url.host() {
+        Some(Host::Ipv4(v4)) => vec![IpAddr::V4(v4)],
+        Some(Host::Ipv6(v6)) => vec![IpAddr::V6(v6)],
+        Some(Host::Domain(domain)) => tokio::net::lookup_host((domain, 0))
+            .await
+            .map_err(|e| AppError::BadRequest(format!("failed to resolve host: {e}")))?
+            .map(|addr| addr.ip())
+            .collect(),
+        None => return Err(AppError::BadRequest("url has no host".to_string())),
+    };
+
+    if addrs.is_empty() || !addrs.iter().all(|ip| is_public_ip(*ip)) {
+        return Err(AppError::BadRequest(
+            "url must be a publicly routable http(s) address".to_string(),
+        ));
+    }
+
+    Ok((url, addrs))
+}
+
+/// Builds a `reqwest::Client` with redirects disabled (so [`fetch_guarded`]
+/// can re-check every hop itself instead of the http client following them
+/// unchecked) whose DNS resolution for `host` is pinned to `addrs` — the
+/// exact addresses [`check_url`] just validated. Re-resolving `host` at
+/// request time would reopen the DNS-rebinding window `check_url` closes: a
+/// name that resolved to a public address during the check could resolve
+/// to an internal one by the time the request is actually sent.
+fn client_pinned_to(host: &str, addrs: &[IpAddr]) -> reqwest::Result<Client> {
+    let socket_addrs: Vec<SocketAddr> = addrs.iter().map(|ip| SocketAddr::new(*ip, 0)).collect();
+    Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .resolve_to_addrs(host, &socket_addrs)
+        .build()
+}
+
+/// Fetches `url` as text on behalf of every outbound fetch this server
+/// makes (unfurling, article import, webhooks): checks [`check_url`] before
+/// the request and again on every redirect hop, and caps the response body
+/// so a large or slow body can't hold an unbounded allocation open. Each
+/// hop's request is pinned to the addresses that hop was validated against,
+/// so nothing is ever fetched from an address this function didn't itself
+/// check.
+pub async fn fetch_guarded(url: &str) -> AppResult<String> {
+    read_capped_text(fetch_guarded_response(url).await?).await
+}
+
+/// Like [`fetch_guarded`], but for a binary payload (a referenced image) and
+/// its declared MIME type, rather than text.
+pub async fn fetch_guarded_bytes(url: &str) -> AppResult<(Vec<u8>, Option<String>)> {
+    let response = fetch_guarded_response(url).await?;
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    Ok((read_capped_bytes(response).await?, content_type))
+}
+
+/// Shared redirect-following core of [`fetch_guarded`] and
+/// [`fetch_guarded_bytes`]: checks [`check_url`] before the request and
+/// again on every redirect hop, pinning each hop's request to the addresses
+/// that hop was validated against, and returns the first non-redirect
+/// response.
+async fn fetch_guarded_response(url: &str) -> AppResult<Response> {
+    let (mut current, mut addrs) = check_url(url).await?;
+
+    for _ in 0..MAX_REDIRECTS {
+        let host = current.host_str().ok_or_else(|| AppError::BadRequest("url has no host".to_string()))?;
+        let client = client_pinned_to(host, &addrs)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("failed to build pinned http client: {e}")))?;
+
+        let response = client
+            .get(current.as_str())
+            .send()
+            .await
+            .map_err(|e| AppError::BadRequest(format!("failed to fetch url: {e}")))?;
+
+        if !response.status().is_redirection() {
+            return Ok(response);
+        }
+
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| AppError::BadRequest("redirect response had no location".to_string()))?;
+        let next = current
+            .join(location)
+            .map_err(|_| AppError::BadRequest("redirect location was not a valid url".to_string()))?;
+        (current, addrs) = check_url(next.as_str()).await?;
+    }
+
+    Err(AppError::BadRequest("too many redirects".to_string()))
+}
+
+async fn read_capped_bytes(response: Response) -> AppResult<Vec<u8>> {
+    let mut body = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| AppError::BadRequest(format!("failed to read response body: {e}")))?;
+        body.extend_from_slice(&chunk);
+        if body.len() > MAX_RESPONSE_BYTES {
+            return Err(AppError::BadRequest("response body exceeded the size limit".to_string()));
+        }
+    }
+
+    Ok(body)
+}
+
+async fn read_capped_text(response: Response) -> AppResult<String> {
+    let body = read_capped_bytes(response).await?;
+    String::from_utf8(body).map_err(|_| AppError::BadRequest("response body was not valid utf-8".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_loopback_literal() {
+        assert!(!is_publicly_routable("http://127.0.0.1/"));
+    }
+
+    #[test]
+    fn rejects_a_private_literal() {
+        assert!(!is_publicly_routable("http://10.0.0.5/"));
+    }
+
+    #[test]
+    fn rejects_a_disallowed_port() {
+        assert!(!is_publicly_routable("http://example.com:5432/"));
+    }
+
+    #[test]
+    fn rejects_a_non_http_scheme() {
+        assert!(!is_publicly_routable("file:///etc/passwd"));
+    }
+
+    #[test]
+    fn accepts_a_public_hostname_on_a_standard_port() {
+        assert!(is_publicly_routable("https://example.com/page"));
+    }
+
+    #[test]
+    fn rejects_an_ipv6_loopback_literal() {
+        assert!(!is_publicly_routable("http://[::1]/"));
+    }
+
+    #[test]
+    fn rejects_an_ipv6_unique_local_literal() {
+        assert!(!is_publicly_routable("http://[fc00::1]/"));
+    }
+
+    #[test]
+    fn rejects_an_ipv6_link_local_literal() {
+        assert!(!is_publicly_routable("http://[fe80::1]/"));
+    }
+
+    #[test]
+    fn rejects_an_ipv4_mapped_metadata_address() {
+        assert!(!is_publicly_routable("http://[::ffff:169.254.169.254]/"));
+    }
+
+    #[test]
+    fn accepts_a_public_ipv6_literal() {
+        assert!(is_publicly_routable("http://[2606:4700:4700::1111]/"));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod config;
+pub mod domain;
+pub mod errors;
+pub mod infrastructure;
+pub mod presentation;
+pub mod startup;
+pub mod usecase;
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use my_blog_backend::config::{AppConfig, LogRotation};
+use my_blog_backend::infrastructure::db;
+use my_blog_backend::infrastructure::alt_text_suggester::{AltTextSuggester, HttpAltTextSuggester, NoopAltTextSuggester};
+use my_blog_backend::infrastructure::antispam_scorer::{AkismetAntispamScorer, AntispamScorer, HeuristicAntispamScorer};
+use my_blog_backend::infrastructure::contact_notifier::LoggingContactNotifier;
+use my_blog_backend::infrastructure::guest_verification::LoggingGuestVerificationSender;
+use my_blog_backend::infrastructure::image_url_provider::{
+    ImageUrlProvider, LocalImageUrlProvider, ProxyImageUrlProvider,
+};
+use my_blog_backend::infrastructure::concurrency_limiter::ConcurrencyLimiter;
+use my_blog_backend::infrastructure::latency_recorder::LatencyRecorder;
+use my_blog_backend::infrastructure::log_retention;
+use my_blog_backend::infrastructure::metrics_recorder::MetricsRecorder;
+use my_blog_backend::infrastructure::rate_limiter::RateLimiter;
+use my_blog_backend::infrastructure::repository_impl::{
+    AnalyticsRepositoryImpl, ArticleLockRepositoryImpl, ArticleNoteRepositoryImpl,
+    ArticlePendingRevisionRepositoryImpl, ArticleRepositoryImpl, ArticleSlugRedirectRepositoryImpl,
+    AuditLogRepositoryImpl, BlockRepositoryImpl,
+    CategoryRepositoryImpl, CommentRepositoryImpl, ContactMessageRepositoryImpl, FollowRepositoryImpl,
+    ImageRepositoryImpl, SitemapRepositoryImpl, TagRepositoryImpl, UserRepositoryImpl,
+};
+use my_blog_backend::infrastructure::runtime_config::{LogFilterReloadHandle, RuntimeConfigHandle, RuntimeSettings};
+use my_blog_backend::infrastructure::ttl_cache::TtlCache;
+use my_blog_backend::presentation::{build_router, AppState};
+use my_blog_backend::startup;
+use my_blog_backend::usecase::{
+    AnalyticsAppService, ArticleAppService, ArticleImportService, ArticleNoteAppService,
+    BlockAppService, CategoryAppService, CommentAppService, ContactAppService, FollowAppService, ImageAppService,
+    RetentionAppService, SitemapAppService, TagAppService, UnfurlAppService, UserAppService,
+};
+use std::time::Duration;
+
+use tracing_subscriber::prelude::*;
+
+/// Long enough to spare the database a full table scan on every crawler
+/// hit, short enough that a newly published article shows up promptly.
+const SITEMAP_CACHE_TTL: Duration = Duration::from_secs(300);
+const DATE_BROWSE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::dotenv().ok();
+
+    let config = AppConfig::from_env();
+
+    // Keep the file-appender guard alive for the process lifetime, or its
+    // background flush thread is dropped and no logs ever reach disk.
+    let (_file_appender_guard, log_filter_handle) = init_tracing(&config);
+    let pool = db::connect(&config.database_url).await?;
+
+    let runtime_config = Arc::new(RuntimeConfigHandle::new(RuntimeSettings::from_env(), Some(log_filter_handle)));
+    spawn_sighup_reload_listener(runtime_config.clone());
+
+    let startup_report = startup::run(&config, &pool).await;
+    startup_report.log_summary();
+    let startup_errors = startup_report.errors();
+    if !startup_errors.is_empty() {
+        anyhow::bail!("startup validation failed: {}", startup_errors.join("; "));
+    }
+
+    let user_repository = Arc::new(UserRepositoryImpl::new(pool.clone()));
+    let article_repository = Arc::new(ArticleRepositoryImpl::new(pool.clone()));
+    let block_repository = Arc::new(BlockRepositoryImpl::new(pool.clone()));
+    let tag_repository = Arc::new(TagRepositoryImpl::new(pool.clone()));
+    let audit_log_repository = Arc::new(AuditLogRepositoryImpl::new(pool.clone()));
+    let image_repository = Arc::new(ImageRepositoryImpl::new(pool.clone()));
+    let follow_repository = Arc::new(FollowRepositoryImpl::new(pool.clone()));
+    let comment_repository = Arc::new(CommentRepositoryImpl::new(pool.clone()));
+    let metrics_recorder = Arc::new(MetricsRecorder::new());
+
+    let article_app_service = ArticleAppService::new(
+        article_repository.clone(),
+        user_repository.clone(),
+        tag_repository.clone(),
+        Arc::new(ArticlePendingRevisionRepositoryImpl::new(pool.clone())),
+        audit_log_repository.clone(),
+        image_repository.clone(),
+        follow_repository.clone(),
+        Arc::new(ArticleLockRepositoryImpl::new(pool.clone())),
+        comment_repository.clone(),
+        Arc::new(ArticleSlugRedirectRepositoryImpl::new(pool.clone())),
+        config.jwt_secret.clone(),
+        metrics_recorder.clone(),
+    );
+    let antispam_scorer: Arc<dyn AntispamScorer> = match &config.antispam.akismet_api_key {
+        Some(api_key) => Arc::new(AkismetAntispamScorer::new(api_key.clone(), config.antispam.site_url.clone())),
+        None => Arc::new(HeuristicAntispamScorer),
+    };
+    let comment_app_service = CommentAppService::new(
+        comment_repository,
+        article_repository.clone(),
+        block_repository.clone(),
+        audit_log_repository.clone(),
+        Arc::new(LoggingGuestVerificationSender),
+        antispam_scorer.clone(),
+        config.jwt_secret.clone(),
+        config.public_base_url.clone(),
+        config.antispam.pending_threshold,
+        config.antispam.spam_threshold,
+        metrics_recorder.clone(),
+    );
+    let image_url_provider: Arc<dyn ImageUrlProvider> = match &config.image_proxy.base_url {
+        Some(base_url) => Arc::new(ProxyImageUrlProvider::new(base_url.clone())),
+        None => Arc::new(LocalImageUrlProvider),
+    };
+    let alt_text_suggester: Arc<dyn AltTextSuggester> = match &config.alt_text.api_url {
+        Some(api_url) => Arc::new(HttpAltTextSuggester::new(api_url.clone(), config.alt_text.api_key.clone())),
+        None => Arc::new(NoopAltTextSuggester),
+    };
+    let image_app_service = ImageAppService::new(
+        image_repository,
+        article_repository.clone(),
+        image_url_provider,
+        config.object_storage.clone(),
+        config.jwt_secret.clone(),
+        alt_text_suggester,
+        metrics_recorder.clone(),
+    );
+    let user_app_service = UserAppService::new(user_repository.clone(), follow_repository.clone());
+    let follow_app_service = FollowAppService::new(follow_repository, user_repository);
+    let block_app_service = BlockAppService::new(block_repository);
+    let article_import_service = ArticleImportService::new(
+        article_app_service.clone(),
+        image_app_service.clone(),
+        config.object_storage.clone(),
+    );
+    let article_note_app_service = ArticleNoteAppService::new(
+        Arc::new(ArticleNoteRepositoryImpl::new(pool.clone())),
+        article_repository.clone(),
+    );
+    let analytics_repository = Arc::new(AnalyticsRepositoryImpl::new(pool.clone()));
+    let analytics_app_service = AnalyticsAppService::new(analytics_repository.clone());
+    let retention_app_service = RetentionAppService::new(audit_log_repository, analytics_repository);
+    let category_app_service =
+        CategoryAppService::new(Arc::new(CategoryRepositoryImpl::new(pool.clone())), article_repository.clone());
+    let sitemap_app_service = SitemapAppService::new(Arc::new(SitemapRepositoryImpl::new(pool.clone())));
+    let tag_app_service = TagAppService::new(tag_repository, article_repository);
+    let unfurl_app_service = UnfurlAppService::new();
+    let contact_app_service = ContactAppService::new(
+        Arc::new(ContactMessageRepositoryImpl::new(pool.clone())),
+        antispam_scorer,
+        Arc::new(LoggingContactNotifier),
+        config.antispam.spam_threshold,
+    );
+
+    let state = AppState {
+        config: config.clone(),
+        rate_limiter: Arc::new(RateLimiter::new()),
+        runtime_config,
+        concurrency_limiter: Arc::new(ConcurrencyLimiter::new(
+            config.concurrency_limit.max_in_flight,
+            config.concurrency_limit.queue_timeout,
+        )),
+        latency_recorder: Arc::new(LatencyRecorder::new()),
+        metrics_recorder,
+        sitemap_cache: Arc::new(TtlCache::new(SITEMAP_CACHE_TTL)),
+        date_browse_cache: Arc::new(TtlCache::new(DATE_BROWSE_CACHE_TTL)),
+        analytics_app_service,
+        article_app_service,
+        article_import_service,
+        article_note_app_service,
+        block_app_service,
+        category_app_service,
+        comment_app_service,
+        contact_app_service,
+        follow_app_service,
+        image_app_service,
+        retention_app_service,
+        sitemap_app_service,
+        tag_app_service,
+        unfurl_app_service,
+        user_app_service,
+    };
+
+    let app = build_router(state);
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", config.server_port)).await?;
+    tracing::info!("listening on {}", listener.local_addr()?);
+    axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>()).await?;
+
+    Ok(())
+}
+
+/// Always logs to stdout; additionally logs to a rotating file when
+/// `LOG_DIR` is configured. Returns the non-blocking writer guard, which
+/// must be held for the process lifetime to keep the flush thread alive,
+/// and a handle that lets [`RuntimeConfigHandle`] push a new log level in
+/// without restarting the process.
+fn init_tracing(config: &AppConfig) -> (Option<tracing_appender::non_blocking::WorkerGuard>, LogFilterReloadHandle) {
+    let stdout_layer = tracing_subscriber::fmt::layer();
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    let (env_filter, reload_handle) = tracing_subscriber::reload::Layer::new(env_filter);
+
+    let Some(file_config) = &config.logging.file_output else {
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(stdout_layer)
+            .init();
+        return (None, reload_handle);
+    };
+
+    log_retention::enforce_retention(
+        &file_config.directory,
+        &file_config.file_name_prefix,
+        file_config.max_files,
+    );
+
+    let rolling_builder = tracing_appender::rolling::Builder::new().filename_prefix(&file_config.file_name_prefix);
+    let rolling_builder = match file_config.rotation {
+        LogRotation::Daily => rolling_builder.rotation(tracing_appender::rolling::Rotation::DAILY),
+        LogRotation::Hourly => rolling_builder.rotation(tracing_appender::rolling::Rotation::HOURLY),
+        LogRotation::Never => rolling_builder.rotation(tracing_appender::rolling::Rotation::NEVER),
+    };
+    let file_appender = rolling_builder
+        .build(&file_config.directory)
+        .expect("failed to initialize rotating log file appender");
+    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+    let file_layer = tracing_subscriber::fmt::layer().with_ansi(false).with_writer(non_blocking);
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(stdout_layer)
+        .with(file_layer)
+        .init();
+
+    (Some(guard), reload_handle)
+}
+
+/// Reloads the runtime-tunable settings whenever the process receives
+/// SIGHUP, so an operator can change log level, rate limits, pagination
+/// caps, or maintenance mode with `kill -HUP` instead of the admin endpoint.
+fn spawn_sighup_reload_listener(runtime_config: Arc<RuntimeConfigHandle>) {
+    tokio::spawn(async move {
+        let Ok(mut sighup) = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) else {
+            tracing::error!("failed to install SIGHUP handler; runtime config can only be reloaded via the admin endpoint");
+            return;
+        };
+
+        loop {
+            sighup.recv().await;
+            tracing::info!("received SIGHUP, reloading runtime config");
+            runtime_config.reload();
+        }
+    });
+}
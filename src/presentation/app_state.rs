@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::config::AppConfig;
+use crate::infrastructure::concurrency_limiter::ConcurrencyLimiter;
+use crate::infrastructure::latency_recorder::LatencyRecorder;
+use crate::infrastructure::metrics_recorder::MetricsRecorder;
+use crate::infrastructure::rate_limiter::RateLimiter;
+use crate::infrastructure::runtime_config::RuntimeConfigHandle;
+use crate::infrastructure::ttl_cache::TtlCache;
+use crate::usecase::{
+    AnalyticsAppService, ArticleAppService, ArticleImportService, ArticleNoteAppService,
+    BlockAppService, CategoryAppService, CommentAppService, ContactAppService, FollowAppService, ImageAppService,
+    RetentionAppService, SitemapAppService, TagAppService, UnfurlAppService, UserAppService,
+};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub config: AppConfig,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub runtime_config: Arc<RuntimeConfigHandle>,
+    pub concurrency_limiter: Arc<ConcurrencyLimiter>,
+    pub latency_recorder: Arc<LatencyRecorder>,
+    pub metrics_recorder: Arc<MetricsRecorder>,
+    /// Rendered sitemap XML, keyed by scope and page; regenerating on every
+    /// crawler hit would mean re-scanning the whole articles table.
+    pub sitemap_cache: Arc<TtlCache<String>>,
+    /// Serialized date-browse responses ("on this day", "by date"), keyed
+    /// by query; these scan the whole articles table, which is wasteful to
+    /// repeat on every hit to an archive widget.
+    pub date_browse_cache: Arc<TtlCache<String>>,
+    pub analytics_app_service: AnalyticsAppService,
+    pub article_app_service: ArticleAppService,
+    pub article_import_service: ArticleImportService,
+    pub article_note_app_service: ArticleNoteAppService,
+    pub block_app_service: BlockAppService,
+    pub category_app_service: CategoryAppService,
+    pub comment_app_service: CommentAppService,
+    pub contact_app_service: ContactAppService,
+    pub follow_app_service: FollowAppService,
+    pub image_app_service: ImageAppService,
+    pub retention_app_service: RetentionAppService,
+    pub sitemap_app_service: SitemapAppService,
+    pub tag_app_service: TagAppService,
+    pub unfurl_app_service: UnfurlAppService,
+    pub user_app_service: UserAppService,
+}
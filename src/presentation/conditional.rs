@@ -0,0 +1,32 @@
+use axum::http::HeaderMap;
+use chrono::{DateTime, Utc};
+
+use crate::errors::{AppError, AppResult};
+
+const IF_UNMODIFIED_SINCE: &str = "if-unmodified-since";
+
+/// Resolves the timestamp a conditional PATCH should be compared against,
+/// preferring the standard `If-Unmodified-Since` header (HTTP-date, second
+/// precision) and falling back to an `expected_updated_at` field carried in
+/// the request body for clients that can round-trip the exact value a GET
+/// returned. Errors out if neither is present, since the write would
+/// otherwise silently skip the optimistic-concurrency check.
+pub fn resolve_expected_updated_at(
+    headers: &HeaderMap,
+    body_token: Option<DateTime<Utc>>,
+) -> AppResult<DateTime<Utc>> {
+    if let Some(value) = headers.get(IF_UNMODIFIED_SINCE) {
+        let value = value
+            .to_str()
+            .map_err(|_| AppError::BadRequest("If-Unmodified-Since header is not valid UTF-8".to_string()))?;
+        let parsed = DateTime::parse_from_rfc2822(value)
+            .map_err(|_| AppError::BadRequest("If-Unmodified-Since header is not a valid HTTP date".to_string()))?;
+        return Ok(parsed.with_timezone(&Utc));
+    }
+
+    body_token.ok_or_else(|| {
+        AppError::BadRequest(
+            "conditional update requires an If-Unmodified-Since header or expected_updated_at field".to_string(),
+        )
+    })
+}
@@ -0,0 +1,33 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::AppState;
+
+/// Like [`AuthUser`], but additionally requires the user to have the
+/// `is_admin` flag set.
+pub struct AdminUser {
+    pub user_id: Uuid,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let auth_user = AuthUser::from_request_parts(parts, state).await?;
+        let user = state.user_app_service.find_by_id(auth_user.user_id).await?;
+
+        if !user.is_admin {
+            return Err(AppError::Forbidden("admin privileges required".to_string()));
+        }
+
+        Ok(AdminUser { user_id: user.id })
+    }
+}
@@ -0,0 +1,39 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::async_trait;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::infrastructure::jwt;
+use crate::presentation::AppState;
+
+/// The authenticated user for the current request, extracted and verified
+/// from the `Authorization: Bearer <token>` header.
+pub struct AuthUser {
+    pub user_id: Uuid,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| AppError::Unauthorized("missing authorization header".to_string()))?;
+
+        let token = header
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| AppError::Unauthorized("expected bearer token".to_string()))?;
+
+        let claims = jwt::verify_access_token(token, &state.config.jwt_secret)
+            .map_err(|_| AppError::Unauthorized("invalid or expired token".to_string()))?;
+
+        Ok(AuthUser { user_id: claims.sub })
+    }
+}
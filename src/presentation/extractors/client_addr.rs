@@ -0,0 +1,25 @@
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::request::Parts;
+use axum::async_trait;
+use std::net::SocketAddr;
+
+use crate::errors::AppError;
+use crate::infrastructure::client_address::resolve_client_ip;
+use crate::presentation::AppState;
+
+/// The address a rate limiter or abuse signal should key this request on:
+/// the real TCP peer address, or (only when `trusted_proxy_hops` is
+/// configured) the `X-Forwarded-For` hop that many trusted proxies back.
+/// See [`resolve_client_ip`] for why the header alone can't be trusted.
+pub struct ClientAddr(pub String);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientAddr {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let peer = parts.extensions.get::<ConnectInfo<SocketAddr>>().map(|info| info.0);
+        let ip = resolve_client_ip(&parts.headers, peer, state.config.trusted_proxy_hops);
+        Ok(ClientAddr(ip))
+    }
+}
@@ -0,0 +1,27 @@
+use axum::async_trait;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::request::Parts;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::presentation::AppState;
+
+/// A comment id path segment, accepting either the current UUID public id
+/// or an integer id carried over from the pre-UUID schema, resolved to the
+/// internal UUID id. A compatibility shim for clients still linking to
+/// comments by their old integer id.
+pub struct CommentIdParam(pub Uuid);
+
+#[async_trait]
+impl FromRequestParts<AppState> for CommentIdParam {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let Path(raw) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(|error| AppError::BadRequest(format!("invalid comment id path segment: {error}")))?;
+
+        let id = state.comment_app_service.resolve_id(&raw).await?;
+        Ok(CommentIdParam(id))
+    }
+}
@@ -0,0 +1,13 @@
+pub mod admin_user;
+pub mod auth_user;
+pub mod client_addr;
+pub mod comment_id_param;
+pub mod optional_auth_user;
+pub mod validated_image;
+
+pub use admin_user::AdminUser;
+pub use auth_user::AuthUser;
+pub use client_addr::ClientAddr;
+pub use comment_id_param::CommentIdParam;
+pub use optional_auth_user::OptionalAuthUser;
+pub use validated_image::ValidatedImage;
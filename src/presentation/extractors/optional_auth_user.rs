@@ -0,0 +1,35 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::async_trait;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::infrastructure::jwt;
+use crate::presentation::AppState;
+
+/// Like [`crate::presentation::extractors::AuthUser`], but for routes that
+/// serve both public and caller-scoped content: a missing, malformed, or
+/// expired bearer token is treated as an anonymous request instead of
+/// rejecting it.
+pub struct OptionalAuthUser(pub Option<Uuid>);
+
+#[async_trait]
+impl FromRequestParts<AppState> for OptionalAuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let Some(token) = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|header| header.strip_prefix("Bearer "))
+        else {
+            return Ok(OptionalAuthUser(None));
+        };
+
+        let user_id = jwt::verify_access_token(token, &state.config.jwt_secret)
+            .ok()
+            .map(|claims| claims.sub);
+        Ok(OptionalAuthUser(user_id))
+    }
+}
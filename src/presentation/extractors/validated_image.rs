@@ -0,0 +1,152 @@
+use axum::async_trait;
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Multipart, Request};
+
+use crate::errors::AppError;
+use crate::presentation::app_state::AppState;
+
+const FILE_FIELD_NAME: &str = "file";
+const SUPPORTED_MIME_TYPES: &[&str] = &["image/png", "image/jpeg", "image/webp", "image/gif"];
+
+/// An image file extracted from a `multipart/form-data` body, validated
+/// against the mime types this service accepts for upload. Every failure
+/// mode (a malformed body, a missing `file` field, an unreadable chunk, an
+/// unsupported content type) is surfaced as a typed [`AppError`] instead of
+/// panicking partway through extraction.
+#[derive(Debug, Clone)]
+pub struct ValidatedImage {
+    pub bytes: Bytes,
+    pub mime_type: String,
+    pub filename: Option<String>,
+}
+
+#[async_trait]
+impl FromRequest<AppState> for ValidatedImage {
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &AppState) -> Result<Self, Self::Rejection> {
+        let multipart = Multipart::from_request(req, state)
+            .await
+            .map_err(|error| AppError::BadRequest(format!("invalid multipart body: {error}")))?;
+        extract_validated_image(multipart).await
+    }
+}
+
+async fn extract_validated_image(mut multipart: Multipart) -> Result<ValidatedImage, AppError> {
+    let field = loop {
+        let field = multipart
+            .next_field()
+            .await
+            .map_err(|error| AppError::BadRequest(format!("unreadable multipart field: {error}")))?
+            .ok_or_else(|| AppError::BadRequest(format!("missing required \"{FILE_FIELD_NAME}\" file field")))?;
+
+        if field.name() == Some(FILE_FIELD_NAME) {
+            break field;
+        }
+    };
+
+    let filename = field.file_name().map(str::to_string);
+    let mime_type = field
+        .content_type()
+        .map(str::to_string)
+        .ok_or_else(|| AppError::BadRequest("file field is missing a content type".to_string()))?;
+
+    if !SUPPORTED_MIME_TYPES.contains(&mime_type.as_str()) {
+        return Err(AppError::BadRequest(format!("unsupported image type \"{mime_type}\"")));
+    }
+
+    let bytes = field
+        .bytes()
+        .await
+        .map_err(|error| AppError::BadRequest(format!("unreadable multipart field: {error}")))?;
+
+    if bytes.is_empty() {
+        return Err(AppError::BadRequest("uploaded file is empty".to_string()));
+    }
+
+    Ok(ValidatedImage {
+        bytes,
+        mime_type,
+        filename,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Multipart;
+    use axum::http::Request;
+
+    use super::*;
+
+    const BOUNDARY: &str = "fixture-boundary";
+
+    fn multipart_request(body: String) -> Request<Body> {
+        Request::builder()
+            .header(
+                "content-type",
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn multipart_from(body: String) -> Multipart {
+        Multipart::from_request(multipart_request(body), &())
+            .await
+            .expect("request has a valid multipart content-type header")
+    }
+
+    fn part(field_name: &str, filename: Option<&str>, content_type: &str, content: &str) -> String {
+        let filename_part = filename.map(|f| format!("; filename=\"{f}\"")).unwrap_or_default();
+        format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field_name}\"{filename_part}\r\nContent-Type: {content_type}\r\n\r\n{content}\r\n"
+        )
+    }
+
+    fn closing() -> String {
+        format!("--{BOUNDARY}--\r\n")
+    }
+
+    #[tokio::test]
+    async fn accepts_a_supported_image_field() {
+        let body = part("file", Some("photo.png"), "image/png", "fake-png-bytes") + &closing();
+        let multipart = multipart_from(body).await;
+
+        let image = extract_validated_image(multipart).await.unwrap();
+
+        assert_eq!(image.mime_type, "image/png");
+        assert_eq!(image.filename, Some("photo.png".to_string()));
+        assert_eq!(&image.bytes[..], b"fake-png-bytes");
+    }
+
+    #[tokio::test]
+    async fn rejects_a_missing_file_field() {
+        let body = part("caption", None, "text/plain", "not a file") + &closing();
+        let multipart = multipart_from(body).await;
+
+        let error = extract_validated_image(multipart).await.unwrap_err();
+
+        assert!(matches!(error, AppError::BadRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn rejects_an_unsupported_mime_type() {
+        let body = part("file", Some("doc.pdf"), "application/pdf", "%PDF-1.4") + &closing();
+        let multipart = multipart_from(body).await;
+
+        let error = extract_validated_image(multipart).await.unwrap_err();
+
+        assert!(matches!(error, AppError::BadRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn rejects_an_empty_file() {
+        let body = part("file", Some("empty.png"), "image/png", "") + &closing();
+        let multipart = multipart_from(body).await;
+
+        let error = extract_validated_image(multipart).await.unwrap_err();
+
+        assert!(matches!(error, AppError::BadRequest(_)));
+    }
+}
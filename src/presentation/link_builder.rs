@@ -0,0 +1,61 @@
+use uuid::Uuid;
+
+use crate::config::{PermalinkConfig, PermalinkStrategy};
+use crate::presentation::paths;
+
+/// Builds absolute, externally-stable links to site content from a single
+/// configurable base URL and permalink strategy, so switching the article
+/// URL scheme later (UUID paths to slug paths, or vice versa) only touches
+/// this module. Currently only the sitemap needs absolute permalinks; any
+/// future syndication feed would build its `<link>` elements the same way.
+#[derive(Debug, Clone)]
+pub struct LinkBuilder {
+    base_url: String,
+    strategy: PermalinkStrategy,
+}
+
+impl LinkBuilder {
+    pub fn new(base_url: String, permalink: &PermalinkConfig) -> Self {
+        Self { base_url, strategy: permalink.strategy }
+    }
+
+    /// Absolute permalink for an article. Falls back to the UUID path when
+    /// the slug strategy is configured but this article has no slug yet.
+    pub fn article_url(&self, article_id: Uuid, slug: Option<&str>) -> String {
+        let path = match (self.strategy, slug) {
+            (PermalinkStrategy::Slug, Some(slug)) => format!("/articles/{slug}"),
+            _ => paths::article(article_id),
+        };
+        format!("{}{path}", self.base_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder(strategy: PermalinkStrategy) -> LinkBuilder {
+        LinkBuilder::new("https://example.com".to_string(), &PermalinkConfig { strategy })
+    }
+
+    #[test]
+    fn uuid_strategy_ignores_the_slug() {
+        let id = Uuid::nil();
+        let url = builder(PermalinkStrategy::Uuid).article_url(id, Some("hello-world"));
+        assert_eq!(url, format!("https://example.com/articles/{id}"));
+    }
+
+    #[test]
+    fn slug_strategy_prefers_the_slug_when_present() {
+        let id = Uuid::nil();
+        let url = builder(PermalinkStrategy::Slug).article_url(id, Some("hello-world"));
+        assert_eq!(url, "https://example.com/articles/hello-world");
+    }
+
+    #[test]
+    fn slug_strategy_falls_back_to_uuid_when_there_is_no_slug_yet() {
+        let id = Uuid::nil();
+        let url = builder(PermalinkStrategy::Slug).article_url(id, None);
+        assert_eq!(url, format!("https://example.com/articles/{id}"));
+    }
+}
@@ -0,0 +1,21 @@
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{header, HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::presentation::AppState;
+
+/// Queues a request for an in-flight slot up to the configured timeout,
+/// shedding load with a 503 and `Retry-After` once the service is still
+/// saturated when it elapses.
+pub async fn concurrency_limit(State(state): State<AppState>, req: Request<Body>, next: Next) -> Response {
+    let Some(_permit) = state.concurrency_limiter.acquire().await else {
+        let mut response = Response::new(Body::from("service temporarily overloaded, please retry"));
+        *response.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+        response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from_static("1"));
+        return response;
+    };
+
+    next.run(req).await
+}
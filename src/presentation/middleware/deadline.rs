@@ -0,0 +1,18 @@
+use std::time::{Duration, Instant};
+
+use axum::body::Body;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::domain::deadline::RequestDeadline;
+
+/// How long a request gets before its queries should be canceled
+/// server-side rather than left running for a client that has likely
+/// already given up.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn propagate_deadline(mut req: Request<Body>, next: Next) -> Response {
+    req.extensions_mut().insert(RequestDeadline(Instant::now() + REQUEST_TIMEOUT));
+    next.run(req).await
+}
@@ -0,0 +1,26 @@
+use axum::body::Body;
+use axum::extract::{MatchedPath, State};
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::time::Instant;
+
+use crate::presentation::AppState;
+
+pub async fn record_latency(State(state): State<AppState>, req: Request<Body>, next: Next) -> Response {
+    let method = req.method().as_str().to_string();
+    let path_pattern = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+
+    let started_at = Instant::now();
+    let response = next.run(req).await;
+
+    state
+        .latency_recorder
+        .record(&format!("{method} {path_pattern}"), started_at.elapsed());
+
+    response
+}
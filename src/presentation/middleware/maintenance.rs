@@ -0,0 +1,24 @@
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+
+use crate::presentation::AppState;
+
+/// Rejects every request with 503 while maintenance mode is on, so an
+/// operator can drain traffic ahead of a disruptive change without
+/// restarting the process; toggled live via [`crate::infrastructure::runtime_config::RuntimeConfigHandle`].
+pub async fn maintenance_mode(State(state): State<AppState>, req: Request<Body>, next: Next) -> Response {
+    if state.runtime_config.current().maintenance_mode {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": "the service is temporarily down for maintenance" })),
+        )
+            .into_response();
+    }
+
+    next.run(req).await
+}
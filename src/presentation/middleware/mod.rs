@@ -0,0 +1,15 @@
+pub mod concurrency_limit;
+pub mod deadline;
+pub mod latency;
+pub mod maintenance;
+pub mod options_handler;
+pub mod problem_json;
+pub mod rate_limit;
+
+pub use concurrency_limit::concurrency_limit;
+pub use deadline::propagate_deadline;
+pub use latency::record_latency;
+pub use maintenance::maintenance_mode;
+pub use options_handler::handle_options;
+pub use problem_json::negotiate_problem_json;
+pub use rate_limit::rate_limit;
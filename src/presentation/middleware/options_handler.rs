@@ -0,0 +1,24 @@
+use axum::extract::Request;
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+
+/// Turns the 405 axum generates for an unregistered `OPTIONS` on a known path
+/// into a 200 with an empty body, keeping the `Allow` header axum already
+/// computed from the route's registered methods.
+///
+/// This runs as the outermost layer (after routing, not via `route_layer`)
+/// so it sees the real 405 response rather than being skipped by it.
+pub async fn handle_options(req: Request, next: Next) -> Response {
+    let is_options = req.method() == Method::OPTIONS;
+    let response = next.run(req).await;
+
+    if is_options && response.status() == StatusCode::METHOD_NOT_ALLOWED {
+        let mut response = response;
+        *response.status_mut() = StatusCode::OK;
+        *response.body_mut() = axum::body::Body::empty();
+        response
+    } else {
+        response
+    }
+}
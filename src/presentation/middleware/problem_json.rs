@@ -0,0 +1,71 @@
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{header, HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::presentation::AppState;
+
+/// An [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457) problem details
+/// body, used in place of the plain `{"error": ...}` shape when the caller
+/// asks for it, so clients and gateways that already understand
+/// `application/problem+json` can interoperate without custom parsing.
+#[derive(Debug, Serialize)]
+struct ProblemDetails {
+    #[serde(rename = "type")]
+    type_uri: String,
+    title: String,
+    status: u16,
+    detail: String,
+}
+
+/// Rewrites an error response's body into [`ProblemDetails`] when the
+/// caller sends `Accept: application/problem+json`, leaving the plain
+/// `{"error": ...}` body (the default, and the only shape this server
+/// shipped before) untouched for everyone else.
+pub async fn negotiate_problem_json(State(state): State<AppState>, req: Request<Body>, next: Next) -> Response {
+    let wants_problem_json = req
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/problem+json"));
+
+    let response = next.run(req).await;
+    if !wants_problem_json || response.status().is_success() {
+        return response;
+    }
+
+    let status = response.status();
+    let (parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return Response::from_parts(parts, Body::empty());
+    };
+
+    let detail = serde_json::from_slice::<Value>(&bytes)
+        .ok()
+        .and_then(|value| value.get("error").and_then(Value::as_str).map(str::to_string));
+
+    let Some(detail) = detail else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let problem = ProblemDetails {
+        type_uri: format!("{}/errors/{}", state.config.public_base_url, status.as_u16()),
+        title: canonical_title(status),
+        status: status.as_u16(),
+        detail,
+    };
+
+    let mut response = (status, Json(problem)).into_response();
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/problem+json"));
+    response
+}
+
+fn canonical_title(status: StatusCode) -> String {
+    status.canonical_reason().unwrap_or("Error").to_string()
+}
@@ -0,0 +1,42 @@
+use std::net::SocketAddr;
+
+use axum::body::Body;
+use axum::extract::{ConnectInfo, MatchedPath, State};
+use axum::http::{header, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::errors::AppError;
+use crate::infrastructure::client_address::resolve_client_ip;
+use crate::presentation::AppState;
+
+pub async fn rate_limit(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    let method = req.method().as_str().to_string();
+    let path_pattern = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+
+    let authenticated = req.headers().get(header::AUTHORIZATION).is_some();
+
+    let peer = req.extensions().get::<ConnectInfo<SocketAddr>>().map(|info| info.0);
+    let client_key = resolve_client_ip(req.headers(), peer, state.config.trusted_proxy_hops);
+
+    let runtime_settings = state.runtime_config.current();
+    let rule = runtime_settings.rate_limit.rule_for(&method, &path_pattern);
+    let limit = rule.effective_limit(authenticated);
+    let bucket_key = format!("{client_key}:{method}:{path_pattern}");
+
+    if !state.rate_limiter.check(&bucket_key, limit) {
+        return Err(AppError::TooManyRequests(format!(
+            "rate limit of {limit} requests/minute exceeded for {method} {path_pattern}"
+        )));
+    }
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,12 @@
+pub mod app_state;
+pub mod conditional;
+pub mod cors;
+pub mod extractors;
+pub mod link_builder;
+pub mod middleware;
+pub mod paths;
+pub mod router;
+pub mod routes;
+
+pub use app_state::AppState;
+pub use router::build_router;
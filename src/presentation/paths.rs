@@ -0,0 +1,95 @@
+//! Route path patterns and link builders, defined once so axum
+//! registration and any future link generation (feeds, sitemaps, a client
+//! crate) can't drift from the paths this server actually serves.
+
+use uuid::Uuid;
+
+pub const ARTICLES: &str = "/articles";
+pub const ARTICLES_VALIDATE: &str = "/articles/validate";
+pub const ARTICLE: &str = "/articles/:article_id";
+pub const ARTICLES_IMPORT_URL: &str = "/articles/import-url";
+pub const ARTICLE_BY_SLUG: &str = "/articles/by-slug/:slug";
+pub const ARTICLES_ON_THIS_DAY: &str = "/articles/on-this-day";
+pub const ARTICLES_BY_DATE: &str = "/articles/by-date/:year/:month";
+pub const ARTICLE_PENDING: &str = "/articles/:article_id/pending";
+pub const ARTICLE_PREVIEW_TOKEN: &str = "/articles/:article_id/preview-token";
+pub const ARTICLE_PUBLISH_PENDING: &str = "/articles/:article_id/publish-pending";
+pub const ARTICLE_COMMENTS: &str = "/articles/:article_id/comments";
+pub const ARTICLE_NOTES: &str = "/articles/:article_id/notes";
+pub const ARTICLE_NOTE: &str = "/notes/:note_id";
+pub const ARTICLE_EVENTS: &str = "/articles/:article_id/events";
+pub const ARTICLE_LOCK: &str = "/articles/:article_id/lock";
+pub const ARTICLE_TRANSFER: &str = "/articles/:article_id/transfer";
+pub const ARTICLE_IMAGES: &str = "/articles/:article_id/images";
+pub const ARTICLE_TAGS: &str = "/articles/:article_id/tags";
+pub const MY_ARTICLE_COMMENTS: &str = "/me/articles/:article_id/comments";
+pub const COMMENTS_VALIDATE: &str = "/comments/validate";
+pub const COMMENT: &str = "/comments/:comment_id";
+pub const COMMENT_VERIFY_EMAIL: &str = "/comments/:comment_id/verify-email";
+pub const MY_BLOCKS: &str = "/me/blocks";
+pub const BLOCK: &str = "/me/blocks/:block_id";
+pub const CATEGORY: &str = "/categories/:category_id";
+pub const CATEGORY_ARTICLES: &str = "/categories/:category_id/articles";
+pub const CATEGORY_OVERVIEW: &str = "/categories/:category_id/overview";
+pub const IMAGES: &str = "/images";
+pub const IMAGE: &str = "/images/:image_id";
+pub const IMAGE_STATUS: &str = "/images/:image_id/status";
+pub const IMAGES_PRESIGN: &str = "/images/presign";
+pub const IMAGES_CONFIRM: &str = "/images/confirm";
+pub const MY_IMAGES: &str = "/me/images";
+pub const MY_PREFERENCES: &str = "/me/preferences";
+pub const UNFURL: &str = "/unfurl";
+pub const CONTACT: &str = "/contact";
+pub const CONTACT_VALIDATE: &str = "/contact/validate";
+pub const ADMIN_CONTACT_MESSAGES: &str = "/admin/contact-messages";
+pub const INTERNAL_STATUS: &str = "/internal/status";
+pub const ADMIN_ANALYTICS_EXPORT: &str = "/admin/analytics/export";
+pub const ADMIN_ARTICLES_RECALCULATE_DERIVED_FIELDS: &str = "/admin/articles/recalculate-derived-fields";
+pub const ADMIN_COMMENTS_ANONYMIZE_GUEST: &str = "/admin/comments/anonymize-guest";
+pub const ADMIN_COMMENTS_SCRUB_PRIVACY_FIELDS: &str = "/admin/comments/scrub-privacy-fields";
+pub const ADMIN_COMMENT_MODERATION: &str = "/admin/comments/:comment_id/moderation";
+pub const ADMIN_COMMENTS_HELD: &str = "/admin/comments/held";
+pub const ADMIN_COMMENT_MODERATION_STATUS: &str = "/admin/comments/:comment_id/moderation-status";
+pub const ADMIN_PERFORMANCE_LATENCY: &str = "/admin/performance/latency";
+pub const ADMIN_PERFORMANCE_METRICS: &str = "/admin/performance/metrics";
+pub const ADMIN_RETENTION: &str = "/admin/retention";
+pub const ADMIN_RETENTION_RUN: &str = "/admin/retention/run";
+pub const ADMIN_RUNTIME_CONFIG: &str = "/admin/runtime-config";
+pub const ADMIN_RUNTIME_CONFIG_RELOAD: &str = "/admin/runtime-config/reload";
+pub const ADMIN_EMAIL_PREVIEW: &str = "/admin/email-preview/:template";
+pub const SITEMAP_INDEX: &str = "/sitemap.xml";
+pub const SITEMAP_CATEGORY: &str = "/sitemap/category/:category_id";
+pub const SITEMAP_TAG: &str = "/sitemap/tag/:tag_id";
+pub const TAG_ATTACH: &str = "/tags/:tag_id/attach";
+pub const TAG_DETACH: &str = "/tags/:tag_id/detach";
+pub const TAG_ARTICLES: &str = "/tags/:tag_id/articles";
+pub const USER_PROFILE: &str = "/users/:user_id/profile";
+pub const USER_FOLLOW: &str = "/users/:user_id/follow";
+pub const MY_FEED: &str = "/me/feed";
+pub const ADMIN_USER_VERIFICATION: &str = "/admin/users/:user_id/verification";
+
+/// Concrete link to a single article, for use outside axum's own router
+/// (feed entries, sitemaps, notification emails).
+pub fn article(article_id: Uuid) -> String {
+    format!("/articles/{article_id}")
+}
+
+pub fn article_comments(article_id: Uuid) -> String {
+    format!("/articles/{article_id}/comments")
+}
+
+pub fn comment(comment_id: Uuid) -> String {
+    format!("/comments/{comment_id}")
+}
+
+pub fn category(category_id: Uuid) -> String {
+    format!("/categories/{category_id}")
+}
+
+pub fn image(image_id: Uuid) -> String {
+    format!("/images/{image_id}")
+}
+
+pub fn block(block_id: Uuid) -> String {
+    format!("/me/blocks/{block_id}")
+}
@@ -0,0 +1,62 @@
+use axum::middleware;
+use axum::Router;
+
+use crate::presentation::app_state::AppState;
+use crate::presentation::cors::{public_cors, restricted_cors};
+use crate::presentation::middleware::{
+    concurrency_limit, handle_options, maintenance_mode, negotiate_problem_json, propagate_deadline, rate_limit,
+    record_latency,
+};
+use crate::presentation::routes::{
+    admin_analytics, admin_articles, admin_comments, admin_contact_messages, admin_email_preview,
+    admin_performance, admin_retention, admin_runtime_config, admin_users, article_notes, articles, blocks,
+    categories, comments, contact, images, internal_status, preferences, sitemap, tags, unfurl, users,
+};
+
+/// Routes that only ever serve public content: readable from any origin.
+fn public_router() -> Router<AppState> {
+    Router::new()
+        .merge(articles::router())
+        .merge(categories::router())
+        .merge(tags::router())
+        .merge(sitemap::router())
+        .merge(images::router())
+        .merge(unfurl::router())
+        .merge(users::router())
+        .merge(contact::router())
+        .layer(public_cors())
+}
+
+/// Routes that carry the refresh-token cookie or otherwise act on
+/// account-scoped state: locked to the configured CORS allow-list.
+fn restricted_router(state: &AppState) -> Router<AppState> {
+    Router::new()
+        .merge(article_notes::router())
+        .merge(blocks::router())
+        .merge(comments::router())
+        .merge(preferences::router())
+        .merge(admin_analytics::router())
+        .merge(admin_articles::router())
+        .merge(admin_comments::router())
+        .merge(admin_contact_messages::router())
+        .merge(admin_email_preview::router())
+        .merge(admin_performance::router())
+        .merge(admin_retention::router())
+        .merge(admin_runtime_config::router())
+        .merge(admin_users::router())
+        .merge(internal_status::router())
+        .layer(restricted_cors(&state.config.cors))
+}
+
+pub fn build_router(state: AppState) -> Router {
+    public_router()
+        .merge(restricted_router(&state))
+        .route_layer(middleware::from_fn_with_state(state.clone(), record_latency))
+        .route_layer(middleware::from_fn_with_state(state.clone(), rate_limit))
+        .route_layer(middleware::from_fn_with_state(state.clone(), maintenance_mode))
+        .route_layer(middleware::from_fn_with_state(state.clone(), concurrency_limit))
+        .route_layer(middleware::from_fn(propagate_deadline))
+        .route_layer(middleware::from_fn_with_state(state.clone(), negotiate_problem_json))
+        .with_state(state)
+        .layer(middleware::from_fn(handle_options))
+}
@@ -0,0 +1,50 @@
+use axum::body::Body;
+use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use chrono::NaiveDate;
+use futures::StreamExt;
+use serde::Deserialize;
+
+use crate::errors::{AppError, AppResult};
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::ADMIN_ANALYTICS_EXPORT, get(export))
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportQuery {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+async fn export(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(query): Query<ExportQuery>,
+) -> AppResult<Response> {
+    if query.from > query.to {
+        return Err(AppError::BadRequest("from must not be after to".to_string()));
+    }
+
+    let csv_stream = state
+        .analytics_app_service
+        .export_daily_metrics_csv(query.from, query.to)
+        .map(|chunk| chunk.map_err(std::io::Error::other));
+
+    let body = Body::from_stream(csv_stream);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"analytics.csv\""),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,41 @@
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+const DEFAULT_BATCH_SIZE: i64 = 500;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(
+        paths::ADMIN_ARTICLES_RECALCULATE_DERIVED_FIELDS,
+        post(recalculate_derived_fields),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct RecalculateDerivedFieldsRequest {
+    batch_size: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct RecalculateDerivedFieldsResponse {
+    processed: u64,
+}
+
+/// Maintenance endpoint for backfilling `slug`/`word_count`/`excerpt` on
+/// existing articles after [`content_derivation`](crate::infrastructure::content_derivation)
+/// gains a new field or its derivation rules change.
+async fn recalculate_derived_fields(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Json(request): Json<RecalculateDerivedFieldsRequest>,
+) -> AppResult<Json<RecalculateDerivedFieldsResponse>> {
+    let batch_size = request.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+    let processed = state.article_app_service.recalculate_derived_fields(batch_size).await?;
+    Ok(Json(RecalculateDerivedFieldsResponse { processed }))
+}
@@ -0,0 +1,99 @@
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{Comment, CommentModerationDetail, CommentModerationStatus};
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::{AdminUser, CommentIdParam};
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ADMIN_COMMENTS_ANONYMIZE_GUEST, post(anonymize_guest))
+        .route(paths::ADMIN_COMMENTS_SCRUB_PRIVACY_FIELDS, post(scrub_privacy_fields))
+        .route(paths::ADMIN_COMMENT_MODERATION, get(moderation_detail))
+        .route(paths::ADMIN_COMMENTS_HELD, get(held_for_moderation))
+        .route(paths::ADMIN_COMMENT_MODERATION_STATUS, post(set_moderation_status))
+}
+
+#[derive(Debug, Deserialize)]
+struct AnonymizeGuestRequest {
+    guest_fingerprint: String,
+}
+
+#[derive(Debug, Serialize)]
+struct AnonymizeGuestResponse {
+    anonymized_count: u64,
+}
+
+/// GDPR erasure tool: scrubs every guest comment tied to a fingerprint,
+/// recording the action to the audit log under the requesting admin.
+async fn anonymize_guest(
+    State(state): State<AppState>,
+    admin: AdminUser,
+    Json(request): Json<AnonymizeGuestRequest>,
+) -> AppResult<Json<AnonymizeGuestResponse>> {
+    let anonymized_count = state
+        .comment_app_service
+        .anonymize_guest(&request.guest_fingerprint, admin.user_id)
+        .await?;
+    Ok(Json(AnonymizeGuestResponse { anonymized_count }))
+}
+
+#[derive(Debug, Serialize)]
+struct ScrubPrivacyFieldsResponse {
+    scrubbed_count: u64,
+}
+
+/// Cleanup pass for the abuse-investigation fields captured on comment
+/// creation: clears `ip_hash`/`user_agent` on every comment older than the
+/// configured retention window. Meant to be triggered on a schedule by an
+/// operator (cron, k8s CronJob) rather than run inline on every request.
+async fn scrub_privacy_fields(State(state): State<AppState>, _admin: AdminUser) -> AppResult<Json<ScrubPrivacyFieldsResponse>> {
+    let scrubbed_count = state
+        .comment_app_service
+        .scrub_stale_privacy_fields(state.config.comment_privacy.ip_retention)
+        .await?;
+    Ok(Json(ScrubPrivacyFieldsResponse { scrubbed_count }))
+}
+
+/// The abuse-investigation fields withheld from a comment's normal JSON
+/// representation: hashed IP and truncated user agent, for moderation use.
+/// Accepts either a comment's UUID public id or its pre-UUID integer id,
+/// same as every other comment-scoped route (see [`CommentIdParam`]).
+async fn moderation_detail(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    CommentIdParam(comment_id): CommentIdParam,
+) -> AppResult<Json<CommentModerationDetail>> {
+    let detail = state.comment_app_service.find_moderation_detail(comment_id).await?;
+    Ok(Json(detail))
+}
+
+/// Comments the antispam scorer held back from public view, for an admin
+/// queue of decisions still needed.
+async fn held_for_moderation(State(state): State<AppState>, _admin: AdminUser) -> AppResult<Json<Vec<Comment>>> {
+    let comments = state.comment_app_service.find_held_for_moderation().await?;
+    Ok(Json(comments))
+}
+
+#[derive(Debug, Deserialize)]
+struct SetModerationStatusRequest {
+    status: CommentModerationStatus,
+}
+
+/// Releases a held comment to the public or confirms it as spam.
+async fn set_moderation_status(
+    State(state): State<AppState>,
+    admin: AdminUser,
+    CommentIdParam(comment_id): CommentIdParam,
+    Json(request): Json<SetModerationStatusRequest>,
+) -> AppResult<Json<Comment>> {
+    let comment = state
+        .comment_app_service
+        .set_moderation_status(comment_id, request.status, admin.user_id)
+        .await?;
+    Ok(Json(comment))
+}
@@ -0,0 +1,20 @@
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::domain::entities::ContactMessage;
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::ADMIN_CONTACT_MESSAGES, get(find_all))
+}
+
+/// Every contact form submission received, including ones the antispam
+/// scorer flagged as spam and withheld from the admin notification.
+async fn find_all(State(state): State<AppState>, _admin: AdminUser) -> AppResult<Json<Vec<ContactMessage>>> {
+    let messages = state.contact_app_service.find_all().await?;
+    Ok(Json(messages))
+}
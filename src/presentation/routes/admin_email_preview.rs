@@ -0,0 +1,44 @@
+use axum::extract::{Path, Query};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::email_templates;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::ADMIN_EMAIL_PREVIEW, get(preview))
+}
+
+#[derive(Debug, Deserialize)]
+struct PreviewQuery {
+    locale: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PreviewResponse {
+    html: String,
+    text: String,
+}
+
+/// Renders a notification email template with sample data so its
+/// HTML/text parts can be reviewed without actually sending anything.
+async fn preview(
+    _admin: AdminUser,
+    Path(template): Path<String>,
+    Query(query): Query<PreviewQuery>,
+) -> AppResult<Json<PreviewResponse>> {
+    let locale = query.locale.as_deref().unwrap_or("en");
+
+    let rendered = email_templates::render_preview(&template, locale)
+        .ok_or_else(|| AppError::NotFound(format!("no email template named '{template}'")))?
+        .map_err(AppError::Internal)?;
+
+    Ok(Json(PreviewResponse {
+        html: rendered.html,
+        text: rendered.text,
+    }))
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+const DEFAULT_WINDOW_MINUTES: u64 = 15;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ADMIN_PERFORMANCE_LATENCY, get(latency_report))
+        .route(paths::ADMIN_PERFORMANCE_METRICS, get(business_metrics))
+}
+
+#[derive(Debug, Deserialize)]
+struct LatencyReportQuery {
+    window_minutes: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct RouteLatency {
+    route: String,
+    count: usize,
+    p50_ms: u128,
+    p95_ms: u128,
+    p99_ms: u128,
+}
+
+#[derive(Debug, Serialize)]
+struct LatencyReportResponse {
+    window_minutes: u64,
+    routes: Vec<RouteLatency>,
+}
+
+/// Self-hosted substitute for a Prometheus histogram query: p50/p95/p99
+/// request latency per route template, computed from the in-process
+/// reservoir in [`LatencyRecorder`](crate::infrastructure::latency_recorder::LatencyRecorder).
+async fn latency_report(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(query): Query<LatencyReportQuery>,
+) -> Json<LatencyReportResponse> {
+    let window_minutes = query.window_minutes.unwrap_or(DEFAULT_WINDOW_MINUTES);
+    let window = Duration::from_secs(window_minutes * 60);
+
+    let mut routes: Vec<RouteLatency> = state
+        .latency_recorder
+        .report(window)
+        .into_iter()
+        .map(|report| RouteLatency {
+            route: report.route,
+            count: report.count,
+            p50_ms: report.p50.as_millis(),
+            p95_ms: report.p95.as_millis(),
+            p99_ms: report.p99.as_millis(),
+        })
+        .collect();
+    routes.sort_by_key(|route| std::cmp::Reverse(route.p99_ms));
+
+    Json(LatencyReportResponse { window_minutes, routes })
+}
+
+#[derive(Debug, Serialize)]
+struct BusinessMetric {
+    name: String,
+    count: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct BusinessMetricsResponse {
+    metrics: Vec<BusinessMetric>,
+}
+
+/// Current value of every business-event counter recorded through
+/// [`MetricsRecorder`](crate::infrastructure::metrics_recorder::MetricsRecorder),
+/// so dashboards can track product health (articles published, comments
+/// created, image bytes uploaded) rather than just request rates.
+async fn business_metrics(State(state): State<AppState>, _admin: AdminUser) -> Json<BusinessMetricsResponse> {
+    let metrics = state
+        .metrics_recorder
+        .snapshot()
+        .into_iter()
+        .map(|(name, count)| BusinessMetric { name, count })
+        .collect();
+
+    Json(BusinessMetricsResponse { metrics })
+}
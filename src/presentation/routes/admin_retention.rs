@@ -0,0 +1,42 @@
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+use crate::usecase::retention_app_service::RetentionPolicyStatus;
+
+const DEFAULT_BATCH_SIZE: i64 = 500;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ADMIN_RETENTION, get(status))
+        .route(paths::ADMIN_RETENTION_RUN, post(run))
+}
+
+/// Every retention policy, its retention window, and the outcome of its
+/// last run (if it has ever run in this process).
+async fn status(_admin: AdminUser, State(state): State<AppState>) -> Json<Vec<RetentionPolicyStatus>> {
+    Json(state.retention_app_service.status())
+}
+
+#[derive(Debug, Deserialize)]
+struct RunRetentionRequest {
+    batch_size: Option<i64>,
+}
+
+/// Runs every retention policy once, batching deletes and logging progress
+/// per batch. Intended to be called on a schedule (cron, k8s CronJob) since
+/// this server has no built-in scheduler of its own.
+async fn run(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Json(request): Json<RunRetentionRequest>,
+) -> AppResult<Json<Vec<RetentionPolicyStatus>>> {
+    let batch_size = request.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+    let statuses = state.retention_app_service.run_all(batch_size).await?;
+    Ok(Json(statuses))
+}
@@ -0,0 +1,28 @@
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::infrastructure::runtime_config::RuntimeSettings;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ADMIN_RUNTIME_CONFIG, get(current))
+        .route(paths::ADMIN_RUNTIME_CONFIG_RELOAD, post(reload))
+}
+
+/// The runtime-tunable settings currently in effect: log level, maintenance
+/// mode, pagination cap, and rate limits.
+async fn current(_admin: AdminUser, State(state): State<AppState>) -> Json<RuntimeSettings> {
+    Json((*state.runtime_config.current()).clone())
+}
+
+/// Re-reads the runtime-tunable settings from the environment and rate
+/// limit config file and applies them immediately, without a restart.
+/// Equivalent to sending the process a SIGHUP.
+async fn reload(_admin: AdminUser, State(state): State<AppState>) -> Json<RuntimeSettings> {
+    state.runtime_config.reload();
+    Json((*state.runtime_config.current()).clone())
+}
@@ -0,0 +1,31 @@
+use axum::extract::{Path, State};
+use axum::routing::put;
+use axum::{Json, Router};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::AuthorProfile;
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AdminUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::ADMIN_USER_VERIFICATION, put(set_verified))
+}
+
+#[derive(Debug, Deserialize)]
+struct SetVerifiedRequest {
+    is_verified: bool,
+}
+
+/// Grants or revokes a user's verification badge.
+async fn set_verified(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Path(user_id): Path<Uuid>,
+    Json(request): Json<SetVerifiedRequest>,
+) -> AppResult<Json<AuthorProfile>> {
+    let profile = state.user_app_service.set_verified(user_id, request.is_verified).await?;
+    Ok(Json(profile))
+}
@@ -0,0 +1,77 @@
+use axum::extract::{Path, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleNote, NewArticleNote};
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ARTICLE_NOTES, get(find_by_article_id).post(create))
+        .route(paths::ARTICLE_NOTE, axum::routing::put(update).delete(delete_note))
+}
+
+async fn find_by_article_id(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<Vec<ArticleNote>>> {
+    let notes = state
+        .article_note_app_service
+        .find_by_article_id(article_id, auth_user.user_id)
+        .await?;
+    Ok(Json(notes))
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateArticleNoteRequest {
+    body: String,
+}
+
+async fn create(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+    Json(payload): Json<CreateArticleNoteRequest>,
+) -> AppResult<Json<ArticleNote>> {
+    let note = state
+        .article_note_app_service
+        .create(NewArticleNote {
+            article_id,
+            author_id: auth_user.user_id,
+            body: payload.body,
+        })
+        .await?;
+    Ok(Json(note))
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateArticleNoteRequest {
+    body: String,
+}
+
+async fn update(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(note_id): Path<Uuid>,
+    Json(payload): Json<UpdateArticleNoteRequest>,
+) -> AppResult<Json<ArticleNote>> {
+    let note = state
+        .article_note_app_service
+        .update_body(note_id, payload.body, auth_user.user_id)
+        .await?;
+    Ok(Json(note))
+}
+
+async fn delete_note(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(note_id): Path<Uuid>,
+) -> AppResult<()> {
+    state.article_note_app_service.delete(note_id, auth_user.user_id).await
+}
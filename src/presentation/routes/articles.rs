@@ -0,0 +1,470 @@
+use axum::body::Body;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::deadline::RequestDeadline;
+use crate::domain::entities::{
+    Article, ArticleLicense, ArticleLock, ArticlePendingRevision, AuditLog, NewArticle, NewArticlePendingRevision,
+};
+use crate::domain::pagination::PagedBody;
+use crate::domain::validation::ValidationViolation;
+use crate::errors::{AppError, AppResult};
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::{AuthUser, OptionalAuthUser};
+use crate::presentation::paths;
+
+/// How many fallback suggestions [`not_found_with_suggestions`] returns.
+const NOT_FOUND_SUGGESTION_LIMIT: i64 = 5;
+
+const DEFAULT_EVENTS_PAGE_SIZE: i64 = 20;
+const MAX_EVENTS_PAGE_SIZE: i64 = 100;
+
+const DEFAULT_DATE_BROWSE_PAGE_SIZE: i64 = 20;
+const MAX_DATE_BROWSE_PAGE_SIZE: i64 = 100;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ARTICLES, get(find_all).post(create))
+        .route(paths::ARTICLES_VALIDATE, axum::routing::post(validate))
+        .route(paths::ARTICLE, get(find_by_id).put(update).delete(delete_article))
+        .route(paths::ARTICLES_IMPORT_URL, axum::routing::post(import_from_url))
+        .route(paths::ARTICLE_BY_SLUG, get(find_by_slug))
+        .route(paths::ARTICLES_ON_THIS_DAY, get(on_this_day))
+        .route(paths::ARTICLES_BY_DATE, get(by_date))
+        .route(paths::ARTICLE_PENDING, get(find_pending).put(save_pending))
+        .route(paths::ARTICLE_PREVIEW_TOKEN, axum::routing::post(issue_preview_token))
+        .route(paths::ARTICLE_PUBLISH_PENDING, axum::routing::post(publish_pending))
+        .route(paths::ARTICLE_EVENTS, get(list_events))
+        .route(paths::ARTICLE_LOCK, axum::routing::post(acquire_lock).delete(release_lock))
+        .route(paths::ARTICLE_TRANSFER, axum::routing::post(transfer_ownership))
+        .route(paths::MY_FEED, get(personalized_feed))
+}
+
+#[derive(Debug, Deserialize)]
+struct FindAllQuery {
+    license: Option<ArticleLicense>,
+}
+
+/// Streams the result as newline-delimited JSON instead of a single
+/// buffered array when the caller sends `Accept: application/x-ndjson`,
+/// so exporting the whole table doesn't require holding it all in memory
+/// at once on either end.
+async fn find_all(
+    State(state): State<AppState>,
+    Query(query): Query<FindAllQuery>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    if wants_ndjson(&headers) {
+        let stream = state
+            .article_app_service
+            .stream_all_ndjson(query.license)
+            .map(|chunk| chunk.map_err(std::io::Error::other));
+        return Ok((
+            [(header::CONTENT_TYPE, "application/x-ndjson")],
+            Body::from_stream(stream),
+        )
+            .into_response());
+    }
+
+    let articles = state.article_app_service.find_all(query.license).await?;
+    Ok(Json(articles).into_response())
+}
+
+fn wants_ndjson(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/x-ndjson"))
+}
+
+/// Published articles from authors the caller follows, most recent first.
+async fn personalized_feed(State(state): State<AppState>, auth_user: AuthUser) -> AppResult<Json<Vec<Article>>> {
+    let articles = state.article_app_service.personalized_feed(auth_user.user_id).await?;
+    Ok(Json(articles))
+}
+
+#[derive(Debug, Deserialize)]
+struct FindByIdQuery {
+    /// When set to `tags`, embeds the article's tags in the response
+    /// instead of requiring a separate request for them.
+    embed: Option<String>,
+    /// A token from [`issue_preview_token`], letting an unauthenticated SSR
+    /// request through to a draft it was issued for.
+    preview_token: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PreviewTokenResponse {
+    preview_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// Mints a short-lived preview token for the article, so an SSR frontend
+/// can link to a draft without the viewer being signed in as its author.
+/// Owner only.
+async fn issue_preview_token(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<PreviewTokenResponse>> {
+    let (preview_token, expires_at) = state
+        .article_app_service
+        .issue_preview_token(article_id, auth_user.user_id)
+        .await?;
+    Ok(Json(PreviewTokenResponse { preview_token, expires_at }))
+}
+
+/// Redirects to the article's canonical `/articles/:article_id` URL,
+/// whether `slug` is its current slug or one it was renamed away from, so a
+/// link or bookmark made before a rename doesn't 404.
+async fn find_by_slug(State(state): State<AppState>, Path(slug): Path<String>) -> AppResult<Response> {
+    let article_id = state.article_app_service.resolve_slug(&slug).await?;
+    let location = paths::article(article_id);
+    Ok((StatusCode::MOVED_PERMANENTLY, [(header::LOCATION, location)]).into_response())
+}
+
+#[derive(Debug, Serialize)]
+struct DateBrowseResponse {
+    items: Vec<Article>,
+    has_next: bool,
+    next_cursor: Option<String>,
+    total: i64,
+}
+
+impl DateBrowseResponse {
+    fn new(page: PagedBody<Article>, total: i64) -> Self {
+        Self {
+            items: page.items,
+            has_next: page.has_next,
+            next_cursor: page.next_cursor,
+            total,
+        }
+    }
+}
+
+fn date_browse_response(body: String) -> Response {
+    ([(header::CONTENT_TYPE, "application/json")], body).into_response()
+}
+
+#[derive(Debug, Deserialize)]
+struct OnThisDayQuery {
+    month: i32,
+    day: i32,
+    per_page: Option<i64>,
+    before: Option<DateTime<Utc>>,
+}
+
+/// Published articles originally posted on this calendar month and day in
+/// any year, newest first, for an "on this day" archive widget. Briefly
+/// cached since it scans the whole articles table.
+async fn on_this_day(State(state): State<AppState>, Query(query): Query<OnThisDayQuery>) -> AppResult<Response> {
+    let per_page = query.per_page.unwrap_or(DEFAULT_DATE_BROWSE_PAGE_SIZE).clamp(1, MAX_DATE_BROWSE_PAGE_SIZE);
+    let cache_key = format!("on-this-day:{}:{}:{per_page}:{:?}", query.month, query.day, query.before);
+    if let Some(cached) = state.date_browse_cache.get(&cache_key) {
+        return Ok(date_browse_response(cached));
+    }
+
+    let (page, total) = state
+        .article_app_service
+        .on_this_day(query.month, query.day, per_page, query.before)
+        .await?;
+    let body =
+        serde_json::to_string(&DateBrowseResponse::new(page, total)).map_err(|e| AppError::Internal(e.into()))?;
+    state.date_browse_cache.insert(cache_key, body.clone());
+    Ok(date_browse_response(body))
+}
+
+#[derive(Debug, Deserialize)]
+struct ByDateQuery {
+    per_page: Option<i64>,
+    before: Option<DateTime<Utc>>,
+}
+
+/// Published articles posted during this calendar year and month, newest
+/// first, for date-based archive browsing. Briefly cached since it scans
+/// the whole articles table.
+async fn by_date(
+    State(state): State<AppState>,
+    Path((year, month)): Path<(i32, i32)>,
+    Query(query): Query<ByDateQuery>,
+) -> AppResult<Response> {
+    let per_page = query.per_page.unwrap_or(DEFAULT_DATE_BROWSE_PAGE_SIZE).clamp(1, MAX_DATE_BROWSE_PAGE_SIZE);
+    let cache_key = format!("by-date:{year}:{month}:{per_page}:{:?}", query.before);
+    if let Some(cached) = state.date_browse_cache.get(&cache_key) {
+        return Ok(date_browse_response(cached));
+    }
+
+    let (page, total) = state.article_app_service.by_date(year, month, per_page, query.before).await?;
+    let body =
+        serde_json::to_string(&DateBrowseResponse::new(page, total)).map_err(|e| AppError::Internal(e.into()))?;
+    state.date_browse_cache.insert(cache_key, body.clone());
+    Ok(date_browse_response(body))
+}
+
+/// Serves the raw markdown body instead of the usual JSON representation
+/// when the caller sends `Accept: text/markdown`, so static site generators
+/// and editors can consume content without unwrapping JSON.
+async fn find_by_id(
+    State(state): State<AppState>,
+    requesting_user: OptionalAuthUser,
+    Path(article_id): Path<Uuid>,
+    Query(query): Query<FindByIdQuery>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    if query.embed.as_deref() == Some("tags") {
+        return match state.article_app_service.find_by_id_with_tags(article_id, requesting_user.0).await {
+            Ok(article_with_tags) => Ok(Json(article_with_tags).into_response()),
+            Err(AppError::NotFound(message)) => not_found_with_suggestions(&state, message).await,
+            Err(error) => Err(error),
+        };
+    }
+
+    let article = match state
+        .article_app_service
+        .find_by_id_with_preview_token(article_id, query.preview_token.as_deref(), requesting_user.0)
+        .await
+    {
+        Ok(article) => article,
+        Err(AppError::NotFound(message)) => return not_found_with_suggestions(&state, message).await,
+        Err(error) => return Err(error),
+    };
+
+    if wants_markdown(&headers) {
+        return Ok((
+            [
+                (header::CONTENT_TYPE, "text/markdown; charset=utf-8"),
+                (header::CACHE_CONTROL, "public, max-age=60"),
+            ],
+            article.body,
+        )
+            .into_response());
+    }
+
+    Ok(Json(article).into_response())
+}
+
+#[derive(Debug, Serialize)]
+struct ArticleNotFoundBody {
+    error: String,
+    /// Recently published articles offered in place of the one that
+    /// couldn't be found, so a frontend can render a helpful not-found
+    /// page instead of a dead end.
+    suggestions: Vec<Article>,
+}
+
+/// Builds the 404 response for a missing article: the usual error message,
+/// plus a handful of suggested articles from a fallback query.
+async fn not_found_with_suggestions(state: &AppState, message: String) -> AppResult<Response> {
+    let suggestions = state
+        .article_app_service
+        .not_found_suggestions(NOT_FOUND_SUGGESTION_LIMIT)
+        .await?;
+    Ok((StatusCode::NOT_FOUND, Json(ArticleNotFoundBody { error: message, suggestions })).into_response())
+}
+
+fn wants_markdown(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/markdown"))
+}
+
+async fn create(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Extension(deadline): Extension<RequestDeadline>,
+    Json(mut new_article): Json<NewArticle>,
+) -> AppResult<Json<Article>> {
+    new_article.user_id = auth_user.user_id;
+    let article = state.article_app_service.create(new_article, deadline).await?;
+    Ok(Json(article))
+}
+
+#[derive(Debug, Deserialize)]
+struct ValidateArticleRequest {
+    title: String,
+    body: String,
+    #[serde(default)]
+    tag_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ValidateArticleResponse {
+    valid: bool,
+    violations: Vec<ValidationViolation>,
+}
+
+/// Runs the exact validation [`create`]/[`update`] enforce, without
+/// persisting anything, so an editor can show inline violations as the
+/// author types instead of only on submit.
+async fn validate(
+    State(state): State<AppState>,
+    Json(payload): Json<ValidateArticleRequest>,
+) -> AppResult<Json<ValidateArticleResponse>> {
+    let violations = state
+        .article_app_service
+        .validate(&payload.title, &payload.body, &payload.tag_ids)
+        .await?;
+    Ok(Json(ValidateArticleResponse {
+        valid: violations.is_empty(),
+        violations,
+    }))
+}
+
+async fn update(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+    Json(mut article): Json<Article>,
+) -> AppResult<Json<Article>> {
+    article.id = article_id;
+    let updated = state.article_app_service.update(article, auth_user.user_id).await?;
+    Ok(Json(updated))
+}
+
+/// Acquires (or renews) the article's advisory edit lock, so another
+/// editor's client can show "someone else is editing this" instead of
+/// conflicting with an in-progress edit.
+async fn acquire_lock(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<ArticleLock>> {
+    let lock = state.article_app_service.acquire_lock(article_id, auth_user.user_id).await?;
+    Ok(Json(lock))
+}
+
+async fn release_lock(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<()> {
+    state.article_app_service.release_lock(article_id, auth_user.user_id).await
+}
+
+#[derive(Debug, Deserialize)]
+struct TransferOwnershipRequest {
+    new_owner_id: Uuid,
+}
+
+/// Reassigns the article to another user, for handing it off when an
+/// author leaves a multi-author blog. Callable by the current owner or an
+/// admin.
+async fn transfer_ownership(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+    Json(request): Json<TransferOwnershipRequest>,
+) -> AppResult<Json<Article>> {
+    let article = state
+        .article_app_service
+        .transfer_ownership(article_id, request.new_owner_id, auth_user.user_id)
+        .await?;
+    Ok(Json(article))
+}
+
+async fn delete_article(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<()> {
+    state.article_app_service.delete(article_id, auth_user.user_id).await
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportUrlRequest {
+    url: String,
+}
+
+async fn import_from_url(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Extension(deadline): Extension<RequestDeadline>,
+    Json(payload): Json<ImportUrlRequest>,
+) -> AppResult<Json<Article>> {
+    let article = state
+        .article_import_service
+        .import_from_url(&payload.url, auth_user.user_id, deadline)
+        .await?;
+    Ok(Json(article))
+}
+
+async fn find_pending(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<ArticlePendingRevision>> {
+    let pending = state.article_app_service.find_pending(article_id, auth_user.user_id).await?;
+    Ok(Json(pending))
+}
+
+#[derive(Debug, Deserialize)]
+struct SavePendingRequest {
+    title: String,
+    body: String,
+    category_id: Option<Uuid>,
+    license: ArticleLicense,
+    attribution: Option<String>,
+}
+
+async fn save_pending(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+    Json(payload): Json<SavePendingRequest>,
+) -> AppResult<Json<ArticlePendingRevision>> {
+    let pending = state
+        .article_app_service
+        .save_pending(
+            NewArticlePendingRevision {
+                article_id,
+                title: payload.title,
+                body: payload.body,
+                category_id: payload.category_id,
+                license: payload.license,
+                attribution: payload.attribution,
+            },
+            auth_user.user_id,
+        )
+        .await?;
+    Ok(Json(pending))
+}
+
+async fn publish_pending(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<Article>> {
+    let article = state.article_app_service.publish_pending(article_id, auth_user.user_id).await?;
+    Ok(Json(article))
+}
+
+#[derive(Debug, Deserialize)]
+struct ListEventsQuery {
+    per_page: Option<i64>,
+    before: Option<DateTime<Utc>>,
+}
+
+/// The article's activity timeline: creation, edits, and publish/unpublish
+/// transitions, newest first. Open to the article's author or an admin.
+async fn list_events(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+    Query(query): Query<ListEventsQuery>,
+) -> AppResult<Json<PagedBody<AuditLog>>> {
+    let max_page_size = MAX_EVENTS_PAGE_SIZE.min(state.runtime_config.current().max_page_size);
+    let per_page = query.per_page.unwrap_or(DEFAULT_EVENTS_PAGE_SIZE).clamp(1, max_page_size);
+    let page = state
+        .article_app_service
+        .list_events(article_id, auth_user.user_id, per_page, query.before)
+        .await?;
+    Ok(Json(page))
+}
@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{Block, NewBlock};
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::MY_BLOCKS, get(find_all).post(create))
+        .route(paths::BLOCK, axum::routing::delete(delete_block))
+}
+
+async fn find_all(State(state): State<AppState>, auth_user: AuthUser) -> AppResult<Json<Vec<Block>>> {
+    let blocks = state.block_app_service.find_by_author(auth_user.user_id).await?;
+    Ok(Json(blocks))
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateBlockRequest {
+    blocked_user_id: Option<Uuid>,
+    blocked_guest_fingerprint: Option<String>,
+}
+
+async fn create(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateBlockRequest>,
+) -> AppResult<Json<Block>> {
+    let block = state
+        .block_app_service
+        .create(NewBlock {
+            author_id: auth_user.user_id,
+            blocked_user_id: payload.blocked_user_id,
+            blocked_guest_fingerprint: payload.blocked_guest_fingerprint,
+        })
+        .await?;
+    Ok(Json(block))
+}
+
+async fn delete_block(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(block_id): Path<Uuid>,
+) -> AppResult<()> {
+    state.block_app_service.delete(auth_user.user_id, block_id).await
+}
@@ -0,0 +1,92 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{Category, CategoryAssignmentResult, CategoryOverview};
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::conditional;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::CATEGORY, get(find_by_id).patch(update_category))
+        .route(paths::CATEGORY_OVERVIEW, get(overview))
+        .route(
+            paths::CATEGORY_ARTICLES,
+            axum::routing::put(assign_articles).delete(remove_articles),
+        )
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateCategoryRequest {
+    name: String,
+    expected_updated_at: Option<DateTime<Utc>>,
+}
+
+async fn find_by_id(State(state): State<AppState>, Path(category_id): Path<Uuid>) -> AppResult<Json<Category>> {
+    let category = state.category_app_service.find_by_id(category_id).await?;
+    Ok(Json(category))
+}
+
+/// The category plus its article count, latest published articles, and
+/// most-used tags, so a landing page can render in a single request.
+async fn overview(State(state): State<AppState>, Path(category_id): Path<Uuid>) -> AppResult<Json<CategoryOverview>> {
+    let overview = state.category_app_service.overview(category_id).await?;
+    Ok(Json(overview))
+}
+
+/// Renames a category, rejecting the write with 412 if it was modified since
+/// the caller's `If-Unmodified-Since` token (header or body field) was issued.
+async fn update_category(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(request): Json<UpdateCategoryRequest>,
+) -> AppResult<Json<Category>> {
+    let expected_updated_at = conditional::resolve_expected_updated_at(&headers, request.expected_updated_at)?;
+    let category = state
+        .category_app_service
+        .update_name(category_id, request.name, expected_updated_at)
+        .await?;
+    Ok(Json(category))
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkCategoryRequest {
+    article_ids: Vec<Uuid>,
+}
+
+/// Assigns the category to every given article owned by the caller in one
+/// transaction, reporting per-article success so backfilling a new
+/// category onto older posts doesn't require editing each one.
+async fn assign_articles(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(category_id): Path<Uuid>,
+    Json(request): Json<BulkCategoryRequest>,
+) -> AppResult<Json<Vec<CategoryAssignmentResult>>> {
+    let results = state
+        .category_app_service
+        .assign_to_articles(category_id, request.article_ids, auth_user.user_id)
+        .await?;
+    Ok(Json(results))
+}
+
+async fn remove_articles(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(category_id): Path<Uuid>,
+    Json(request): Json<BulkCategoryRequest>,
+) -> AppResult<Json<Vec<CategoryAssignmentResult>>> {
+    let results = state
+        .category_app_service
+        .remove_from_articles(category_id, request.article_ids, auth_user.user_id)
+        .await?;
+    Ok(Json(results))
+}
@@ -0,0 +1,141 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{Comment, NewComment};
+use crate::domain::validation::ValidationViolation;
+use crate::errors::{AppError, AppResult};
+use crate::presentation::app_state::AppState;
+use crate::presentation::conditional;
+use crate::presentation::extractors::{AuthUser, ClientAddr, CommentIdParam, OptionalAuthUser};
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::ARTICLE_COMMENTS, get(find_by_article_id).post(create))
+        .route(paths::COMMENTS_VALIDATE, axum::routing::post(validate))
+        .route(
+            paths::COMMENT,
+            axum::routing::patch(update_comment).delete(delete_comment),
+        )
+        .route(paths::MY_ARTICLE_COMMENTS, get(find_by_article_id_as_author))
+        .route(paths::COMMENT_VERIFY_EMAIL, get(verify_email))
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateCommentRequest {
+    body: String,
+    expected_updated_at: Option<DateTime<Utc>>,
+}
+
+async fn find_by_article_id(
+    State(state): State<AppState>,
+    requesting_user: OptionalAuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<Vec<Comment>>> {
+    state.article_app_service.find_viewable(article_id, requesting_user.0).await?;
+    let comments = state.comment_app_service.find_by_article_id(article_id).await?;
+    Ok(Json(comments))
+}
+
+async fn create(
+    State(state): State<AppState>,
+    client_addr: ClientAddr,
+    headers: HeaderMap,
+    Json(mut new_comment): Json<NewComment>,
+) -> AppResult<Json<Comment>> {
+    new_comment.client_ip = Some(client_addr.0);
+    new_comment.user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let comment = state.comment_app_service.create(new_comment).await?;
+    Ok(Json(comment))
+}
+
+#[derive(Debug, Deserialize)]
+struct ValidateCommentRequest {
+    body: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ValidateCommentResponse {
+    valid: bool,
+    violations: Vec<ValidationViolation>,
+}
+
+/// Runs the exact validation [`create`]/[`update_comment`] enforce, without
+/// persisting anything, so a comment form can show violations inline.
+async fn validate(
+    State(state): State<AppState>,
+    Json(payload): Json<ValidateCommentRequest>,
+) -> AppResult<Json<ValidateCommentResponse>> {
+    let violations = state.comment_app_service.validate(&payload.body);
+    Ok(Json(ValidateCommentResponse {
+        valid: violations.is_empty(),
+        violations,
+    }))
+}
+
+/// Edits a comment's body, rejecting the write with 412 if the comment was
+/// modified since the caller's `If-Unmodified-Since` token (header or body
+/// field) was issued.
+async fn update_comment(
+    State(state): State<AppState>,
+    CommentIdParam(comment_id): CommentIdParam,
+    headers: HeaderMap,
+    Json(request): Json<UpdateCommentRequest>,
+) -> AppResult<Json<Comment>> {
+    let expected_updated_at = conditional::resolve_expected_updated_at(&headers, request.expected_updated_at)?;
+    let comment = state
+        .comment_app_service
+        .update_body(comment_id, request.body, expected_updated_at)
+        .await?;
+    Ok(Json(comment))
+}
+
+async fn delete_comment(
+    State(state): State<AppState>,
+    CommentIdParam(comment_id): CommentIdParam,
+) -> AppResult<()> {
+    state.comment_app_service.delete(comment_id).await
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyEmailQuery {
+    token: String,
+}
+
+/// Redeems the one-click link sent to a guest commenter's email address.
+async fn verify_email(
+    State(state): State<AppState>,
+    CommentIdParam(comment_id): CommentIdParam,
+    Query(query): Query<VerifyEmailQuery>,
+) -> AppResult<Json<Comment>> {
+    let comment = state.comment_app_service.verify_guest_email(comment_id, &query.token).await?;
+    Ok(Json(comment))
+}
+
+/// Like [`find_by_article_id`], but hides comments from users or guests the
+/// requesting author has blocked. Only the article's own author may use it.
+async fn find_by_article_id_as_author(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<Vec<Comment>>> {
+    let article = state.article_app_service.find_by_id(article_id).await?;
+    if article.user_id != auth_user.user_id {
+        return Err(AppError::Forbidden("not the author of this article".to_string()));
+    }
+
+    let comments = state
+        .comment_app_service
+        .find_by_article_id_visible_to_author(article_id)
+        .await?;
+    Ok(Json(comments))
+}
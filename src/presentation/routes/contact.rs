@@ -0,0 +1,60 @@
+use axum::extract::State;
+use axum::http::{header, HeaderMap};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{ContactMessage, NewContactMessage};
+use crate::domain::validation::ValidationViolation;
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::ClientAddr;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::CONTACT, post(submit))
+        .route(paths::CONTACT_VALIDATE, post(validate))
+}
+
+async fn submit(
+    State(state): State<AppState>,
+    client_addr: ClientAddr,
+    headers: HeaderMap,
+    Json(mut message): Json<NewContactMessage>,
+) -> AppResult<Json<ContactMessage>> {
+    message.client_ip = Some(client_addr.0);
+    message.user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let message = state.contact_app_service.submit(message).await?;
+    Ok(Json(message))
+}
+
+#[derive(Debug, Deserialize)]
+struct ValidateContactMessageRequest {
+    name: String,
+    email: String,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ValidateContactMessageResponse {
+    valid: bool,
+    violations: Vec<ValidationViolation>,
+}
+
+/// Runs the exact validation [`submit`] enforces, without persisting
+/// anything, so the contact form can show violations inline.
+async fn validate(
+    State(state): State<AppState>,
+    Json(payload): Json<ValidateContactMessageRequest>,
+) -> AppResult<Json<ValidateContactMessageResponse>> {
+    let violations = state.contact_app_service.validate(&payload.name, &payload.email, &payload.message);
+    Ok(Json(ValidateContactMessageResponse {
+        valid: violations.is_empty(),
+        violations,
+    }))
+}
@@ -0,0 +1,198 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{Image, ImageDeletionResult, ImageListFilter, ImageProcessingStatus};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::image_url_provider::ImageTransform;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::{AuthUser, OptionalAuthUser};
+use crate::presentation::paths;
+use crate::usecase::PresignedUpload;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::IMAGES, post(upload))
+        .route(paths::IMAGE, get(find_by_id).patch(reassign))
+        .route(paths::IMAGE_STATUS, get(processing_status))
+        .route(paths::MY_IMAGES, get(find_by_owner))
+        .route(paths::ARTICLE_IMAGES, delete(delete_by_article))
+        .route(paths::IMAGES_PRESIGN, post(presign_upload))
+        .route(paths::IMAGES_CONFIRM, post(confirm_upload))
+}
+
+#[derive(Debug, Deserialize)]
+struct UploadImageRequest {
+    url: String,
+    mime_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ImageStatusResponse {
+    id: Uuid,
+    processing_status: ImageProcessingStatus,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReassignImageRequest {
+    article_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PresignUploadRequest {
+    mime_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfirmUploadRequest {
+    upload_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListImagesQuery {
+    attached: Option<bool>,
+    mime_type: Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    width: Option<u32>,
+    format: Option<String>,
+}
+
+/// Lists the caller's images across all their articles and the unattached
+/// library, for an editor "reuse existing image" picker.
+async fn find_by_owner(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<ListImagesQuery>,
+) -> AppResult<Json<Vec<Image>>> {
+    let filter = ImageListFilter {
+        attached: query.attached,
+        mime_type: query.mime_type,
+        from: query.from,
+        to: query.to,
+        limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE),
+        offset: query.offset.unwrap_or(0).max(0),
+    };
+
+    let transform = ImageTransform {
+        width: query.width,
+        format: query.format,
+    };
+    let images = state
+        .image_app_service
+        .find_by_owner(auth_user.user_id, filter, transform)
+        .await?;
+    Ok(Json(images))
+}
+
+/// Returns an image by id, if `requesting_user` is allowed to view it: images
+/// attached to a published article are public, while images on a draft or
+/// private article (or not yet attached to any article) are visible only to
+/// their owner.
+async fn find_by_id(
+    State(state): State<AppState>,
+    requesting_user: OptionalAuthUser,
+    Path(image_id): Path<Uuid>,
+) -> AppResult<Json<Image>> {
+    let image = state.image_app_service.find_viewable(image_id, requesting_user.0).await?;
+    Ok(Json(image))
+}
+
+/// Reattaches an image to another of the caller's articles, or detaches it
+/// into the unattached library when `article_id` is omitted or `null`.
+async fn reassign(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(image_id): Path<Uuid>,
+    Json(request): Json<ReassignImageRequest>,
+) -> AppResult<Json<Image>> {
+    let image = state
+        .image_app_service
+        .reassign(image_id, auth_user.user_id, request.article_id)
+        .await?;
+    Ok(Json(image))
+}
+
+/// Registers an image for background processing and returns immediately
+/// with `processing_status: pending`; poll [`processing_status`] for
+/// completion.
+async fn upload(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(request): Json<UploadImageRequest>,
+) -> AppResult<(StatusCode, Json<Image>)> {
+    let image = state
+        .image_app_service
+        .upload(auth_user.user_id, request.url, request.mime_type)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(image)))
+}
+
+/// Deletes every image attached to an article in one transaction, e.g. when
+/// discarding a draft. Only the article's owner may call this; the same
+/// cleanup runs automatically when the article itself is hard-deleted.
+async fn delete_by_article(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+) -> AppResult<Json<Vec<ImageDeletionResult>>> {
+    let results = state
+        .image_app_service
+        .delete_by_article(article_id, auth_user.user_id)
+        .await?;
+    Ok(Json(results))
+}
+
+/// Presigns a direct-to-bucket upload for a large image; the caller must
+/// PUT the file to `upload_url` and then call [`confirm_upload`] with the
+/// returned token before the image appears anywhere.
+async fn presign_upload(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(request): Json<PresignUploadRequest>,
+) -> AppResult<Json<PresignedUpload>> {
+    let presigned = state
+        .image_app_service
+        .presign_upload(auth_user.user_id, &request.mime_type)?;
+    Ok(Json(presigned))
+}
+
+/// Confirms a direct upload completed successfully and registers it as a
+/// new image, once the server has independently verified the object's
+/// existence, size, and content type against the bucket.
+async fn confirm_upload(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(request): Json<ConfirmUploadRequest>,
+) -> AppResult<(StatusCode, Json<Image>)> {
+    let image = state
+        .image_app_service
+        .confirm_upload(&request.upload_token, auth_user.user_id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(image)))
+}
+
+async fn processing_status(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(image_id): Path<Uuid>,
+) -> AppResult<Json<ImageStatusResponse>> {
+    let image = state.image_app_service.find_by_id(image_id).await?;
+    if image.user_id != auth_user.user_id {
+        return Err(AppError::Forbidden("not the owner of this image".to_string()));
+    }
+
+    Ok(Json(ImageStatusResponse {
+        id: image.id,
+        processing_status: image.processing_status,
+    }))
+}
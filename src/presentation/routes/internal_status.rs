@@ -0,0 +1,17 @@
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::config::RateLimitConfig;
+use crate::presentation::app_state::AppState;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::INTERNAL_STATUS, get(status))
+}
+
+/// Exposes the effective runtime configuration for operational verification.
+/// Intended to be reachable only from internal networks, not end users.
+async fn status(State(state): State<AppState>) -> Json<RateLimitConfig> {
+    Json((*state.runtime_config.current().rate_limit).clone())
+}
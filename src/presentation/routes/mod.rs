@@ -0,0 +1,22 @@
+pub mod admin_analytics;
+pub mod admin_articles;
+pub mod admin_comments;
+pub mod admin_contact_messages;
+pub mod admin_email_preview;
+pub mod admin_performance;
+pub mod admin_retention;
+pub mod admin_runtime_config;
+pub mod admin_users;
+pub mod article_notes;
+pub mod articles;
+pub mod blocks;
+pub mod categories;
+pub mod comments;
+pub mod contact;
+pub mod images;
+pub mod internal_status;
+pub mod preferences;
+pub mod sitemap;
+pub mod tags;
+pub mod unfurl;
+pub mod users;
@@ -0,0 +1,46 @@
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::domain::entities::{ArticleStatus, UserPreferences};
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::paths;
+use uuid::Uuid;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::MY_PREFERENCES, get(find).put(update))
+}
+
+async fn find(State(state): State<AppState>, auth_user: AuthUser) -> AppResult<Json<UserPreferences>> {
+    let preferences = state.user_app_service.get_preferences(auth_user.user_id).await?;
+    Ok(Json(preferences))
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdatePreferencesRequest {
+    default_article_status: ArticleStatus,
+    default_category_id: Option<Uuid>,
+    timezone: String,
+    locale: String,
+}
+
+async fn update(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(payload): Json<UpdatePreferencesRequest>,
+) -> AppResult<Json<UserPreferences>> {
+    let preferences = state
+        .user_app_service
+        .update_preferences(
+            auth_user.user_id,
+            payload.default_article_status,
+            payload.default_category_id,
+            payload.timezone,
+            payload.locale,
+        )
+        .await?;
+    Ok(Json(preferences))
+}
@@ -0,0 +1,131 @@
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::SitemapEntry;
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::link_builder::LinkBuilder;
+use crate::presentation::paths;
+
+/// Kept comfortably under the sitemap protocol's 50,000-URL-per-file limit.
+const SITEMAP_PAGE_SIZE: i64 = 45_000;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::SITEMAP_INDEX, get(index))
+        .route(paths::SITEMAP_CATEGORY, get(category_sitemap))
+        .route(paths::SITEMAP_TAG, get(tag_sitemap))
+}
+
+#[derive(Debug, Deserialize)]
+struct PageQuery {
+    #[serde(default)]
+    page: i64,
+}
+
+fn page_count(article_count: i64) -> i64 {
+    ((article_count - 1) / SITEMAP_PAGE_SIZE + 1).max(1)
+}
+
+fn xml_response(body: String) -> Response {
+    ([(header::CONTENT_TYPE, "application/xml")], body).into_response()
+}
+
+/// Lists every paginated category and tag sitemap file, so crawlers never
+/// have to guess how many pages a scope needs.
+async fn index(State(state): State<AppState>) -> AppResult<Response> {
+    if let Some(cached) = state.sitemap_cache.get("index") {
+        return Ok(xml_response(cached));
+    }
+
+    let (category_counts, tag_counts) = tokio::try_join!(
+        state.sitemap_app_service.category_article_counts(),
+        state.sitemap_app_service.tag_article_counts(),
+    )?;
+    let base_url = &state.config.public_base_url;
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for (category_id, count) in &category_counts {
+        for page in 0..page_count(*count) {
+            xml.push_str(&format!(
+                "  <sitemap><loc>{base_url}{}?page={page}</loc></sitemap>\n",
+                paths::SITEMAP_CATEGORY.replace(":category_id", &category_id.to_string()),
+            ));
+        }
+    }
+    for (tag_id, count) in &tag_counts {
+        for page in 0..page_count(*count) {
+            xml.push_str(&format!(
+                "  <sitemap><loc>{base_url}{}?page={page}</loc></sitemap>\n",
+                paths::SITEMAP_TAG.replace(":tag_id", &tag_id.to_string()),
+            ));
+        }
+    }
+    xml.push_str("</sitemapindex>\n");
+
+    state.sitemap_cache.insert("index".to_string(), xml.clone());
+    Ok(xml_response(xml))
+}
+
+async fn category_sitemap(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+    Query(query): Query<PageQuery>,
+) -> AppResult<Response> {
+    let cache_key = format!("category:{category_id}:{}", query.page);
+    if let Some(cached) = state.sitemap_cache.get(&cache_key) {
+        return Ok(xml_response(cached));
+    }
+
+    let entries = state
+        .sitemap_app_service
+        .category_page(category_id, SITEMAP_PAGE_SIZE, query.page * SITEMAP_PAGE_SIZE)
+        .await?;
+    let link_builder = LinkBuilder::new(state.config.public_base_url.clone(), &state.config.permalink);
+    let xml = render_urlset(&entries, &link_builder);
+
+    state.sitemap_cache.insert(cache_key, xml.clone());
+    Ok(xml_response(xml))
+}
+
+async fn tag_sitemap(
+    State(state): State<AppState>,
+    Path(tag_id): Path<Uuid>,
+    Query(query): Query<PageQuery>,
+) -> AppResult<Response> {
+    let cache_key = format!("tag:{tag_id}:{}", query.page);
+    if let Some(cached) = state.sitemap_cache.get(&cache_key) {
+        return Ok(xml_response(cached));
+    }
+
+    let entries = state
+        .sitemap_app_service
+        .tag_page(tag_id, SITEMAP_PAGE_SIZE, query.page * SITEMAP_PAGE_SIZE)
+        .await?;
+    let link_builder = LinkBuilder::new(state.config.public_base_url.clone(), &state.config.permalink);
+    let xml = render_urlset(&entries, &link_builder);
+
+    state.sitemap_cache.insert(cache_key, xml.clone());
+    Ok(xml_response(xml))
+}
+
+fn render_urlset(entries: &[SitemapEntry], link_builder: &LinkBuilder) -> String {
+    let mut xml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for entry in entries {
+        xml.push_str(&format!(
+            "  <url><loc>{}</loc><lastmod>{}</lastmod></url>\n",
+            link_builder.article_url(entry.article_id, entry.slug.as_deref()),
+            entry.updated_at.to_rfc3339(),
+        ));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
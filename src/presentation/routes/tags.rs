@@ -0,0 +1,116 @@
+use axum::extract::{Path, Query, State};
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{Article, Tag, TagAttachmentResult};
+use crate::domain::pagination::PagedBody;
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::paths;
+
+const DEFAULT_ARTICLES_PAGE_SIZE: i64 = 20;
+const MAX_ARTICLES_PAGE_SIZE: i64 = 100;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::TAG_ATTACH, post(attach))
+        .route(paths::TAG_DETACH, post(detach))
+        .route(paths::TAG_ARTICLES, get(list_articles))
+        .route(paths::ARTICLE_TAGS, put(set_article_tags))
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkTagRequest {
+    article_ids: Vec<Uuid>,
+}
+
+/// Attaches the tag to every given article owned by the caller in one
+/// transaction, reporting per-article success so a retag across a series
+/// of posts doesn't require editing each one.
+async fn attach(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(tag_id): Path<Uuid>,
+    Json(request): Json<BulkTagRequest>,
+) -> AppResult<Json<Vec<TagAttachmentResult>>> {
+    let results = state
+        .tag_app_service
+        .attach_to_articles(tag_id, request.article_ids, auth_user.user_id)
+        .await?;
+    Ok(Json(results))
+}
+
+async fn detach(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(tag_id): Path<Uuid>,
+    Json(request): Json<BulkTagRequest>,
+) -> AppResult<Json<Vec<TagAttachmentResult>>> {
+    let results = state
+        .tag_app_service
+        .detach_from_articles(tag_id, request.article_ids, auth_user.user_id)
+        .await?;
+    Ok(Json(results))
+}
+
+#[derive(Debug, Deserialize)]
+struct SetArticleTagsRequest {
+    tag_ids: Vec<Uuid>,
+}
+
+/// Replaces the article's tags with exactly the given set in one
+/// transaction, so repeatedly saving the same tags from an editor doesn't
+/// delete-and-reinsert attachments that didn't actually change.
+async fn set_article_tags(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(article_id): Path<Uuid>,
+    Json(request): Json<SetArticleTagsRequest>,
+) -> AppResult<Json<Vec<Tag>>> {
+    let tags = state
+        .tag_app_service
+        .set_article_tags(article_id, request.tag_ids, auth_user.user_id)
+        .await?;
+    Ok(Json(tags))
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTagArticlesQuery {
+    per_page: Option<i64>,
+    before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+struct TagArticlesResponse {
+    items: Vec<Article>,
+    has_next: bool,
+    next_cursor: Option<String>,
+    total: i64,
+}
+
+impl TagArticlesResponse {
+    fn new(page: PagedBody<Article>, total: i64) -> Self {
+        Self {
+            items: page.items,
+            has_next: page.has_next,
+            next_cursor: page.next_cursor,
+            total,
+        }
+    }
+}
+
+/// Published articles carrying this tag, newest first, for a tag's public
+/// browse/archive page.
+async fn list_articles(
+    State(state): State<AppState>,
+    Path(tag_id): Path<Uuid>,
+    Query(query): Query<ListTagArticlesQuery>,
+) -> AppResult<Json<TagArticlesResponse>> {
+    let per_page = query.per_page.unwrap_or(DEFAULT_ARTICLES_PAGE_SIZE).clamp(1, MAX_ARTICLES_PAGE_SIZE);
+    let (page, total) = state.tag_app_service.list_articles(tag_id, per_page, query.before).await?;
+    Ok(Json(TagArticlesResponse::new(page, total)))
+}
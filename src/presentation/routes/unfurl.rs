@@ -0,0 +1,23 @@
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::errors::AppResult;
+use crate::presentation::app_state::AppState;
+use crate::presentation::paths;
+use crate::usecase::unfurl_app_service::LinkPreview;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(paths::UNFURL, get(unfurl))
+}
+
+#[derive(Debug, Deserialize)]
+struct UnfurlQuery {
+    url: String,
+}
+
+async fn unfurl(State(state): State<AppState>, Query(query): Query<UnfurlQuery>) -> AppResult<Json<LinkPreview>> {
+    let preview = state.unfurl_app_service.unfurl(&query.url).await?;
+    Ok(Json(preview))
+}
@@ -0,0 +1,69 @@
+use axum::extract::{Path, State};
+use axum::routing::{get, put};
+use axum::{Json, Router};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{AuthorProfile, FollowStatus};
+use crate::errors::{AppError, AppResult};
+use crate::presentation::app_state::AppState;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::paths;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(paths::USER_PROFILE, get(find).patch(update))
+        .route(paths::USER_FOLLOW, put(follow).delete(unfollow))
+}
+
+/// A user's public profile: display name, verification badge, and bio/links,
+/// for profile pages and article author embeds.
+async fn find(State(state): State<AppState>, Path(user_id): Path<Uuid>) -> AppResult<Json<AuthorProfile>> {
+    let profile = state.user_app_service.get_profile(user_id).await?;
+    Ok(Json(profile))
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateProfileRequest {
+    bio: Option<String>,
+    website: Option<String>,
+    #[serde(default)]
+    social_links: Vec<String>,
+}
+
+/// Self-service update of display metadata; distinct from the account's
+/// name, which isn't editable through this endpoint.
+async fn update(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<UpdateProfileRequest>,
+) -> AppResult<Json<AuthorProfile>> {
+    if auth_user.user_id != user_id {
+        return Err(AppError::Forbidden("cannot edit another user's profile".to_string()));
+    }
+
+    let profile = state
+        .user_app_service
+        .update_profile(user_id, payload.bio, payload.website, payload.social_links)
+        .await?;
+    Ok(Json(profile))
+}
+
+async fn follow(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(user_id): Path<Uuid>,
+) -> AppResult<Json<FollowStatus>> {
+    let status = state.follow_app_service.follow(auth_user.user_id, user_id).await?;
+    Ok(Json(status))
+}
+
+async fn unfollow(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(user_id): Path<Uuid>,
+) -> AppResult<Json<FollowStatus>> {
+    let status = state.follow_app_service.unfollow(auth_user.user_id, user_id).await?;
+    Ok(Json(status))
+}
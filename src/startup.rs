@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+
+use sqlx::PgPool;
+
+use crate::config::AppConfig;
+
+const MIN_JWT_SECRET_LENGTH: usize = 32;
+
+const EXPECTED_ARTICLE_STATUS_LABELS: &[&str] = &["draft", "private", "published"];
+const EXPECTED_ARTICLE_LICENSE_LABELS: &[&str] = &[
+    "all_rights_reserved",
+    "cc_by",
+    "cc_by_sa",
+    "cc_by_nc",
+    "cc_by_nd",
+    "cc_by_nc_sa",
+    "cc_by_nc_nd",
+    "cc0",
+    "public_domain",
+];
+const EXPECTED_ARTICLES_COLUMNS: &[&str] = &[
+    "id",
+    "user_id",
+    "title",
+    "body",
+    "status",
+    "category_id",
+    "license",
+    "attribution",
+    "slug",
+    "word_count",
+    "excerpt",
+];
+
+#[derive(Debug)]
+pub enum DiagnosticStatus {
+    Ok(String),
+    Warning(String),
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub check: &'static str,
+    pub status: DiagnosticStatus,
+}
+
+impl Diagnostic {
+    fn ok(check: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: DiagnosticStatus::Ok(detail.into()),
+        }
+    }
+
+    fn warning(check: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: DiagnosticStatus::Warning(detail.into()),
+        }
+    }
+
+    fn error(check: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: DiagnosticStatus::Error(detail.into()),
+        }
+    }
+}
+
+/// Aggregates every startup diagnostic so boot can fail fast with the full
+/// list of problems, rather than panicking on whichever `expect` happens to
+/// run first.
+pub struct StartupReport {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl StartupReport {
+    pub fn errors(&self) -> Vec<&str> {
+        self.diagnostics
+            .iter()
+            .filter_map(|diagnostic| match &diagnostic.status {
+                DiagnosticStatus::Error(detail) => Some(detail.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Logs every check at a level matching its status, followed by a
+    /// one-line summary of how many passed, warned, or failed.
+    pub fn log_summary(&self) {
+        let mut ok_count = 0;
+        let mut warning_count = 0;
+        let mut error_count = 0;
+
+        for diagnostic in &self.diagnostics {
+            match &diagnostic.status {
+                DiagnosticStatus::Ok(detail) => {
+                    ok_count += 1;
+                    tracing::info!(check = diagnostic.check, "{detail}");
+                }
+                DiagnosticStatus::Warning(detail) => {
+                    warning_count += 1;
+                    tracing::warn!(check = diagnostic.check, "{detail}");
+                }
+                DiagnosticStatus::Error(detail) => {
+                    error_count += 1;
+                    tracing::error!(check = diagnostic.check, "{detail}");
+                }
+            }
+        }
+
+        tracing::info!(ok_count, warning_count, error_count, "startup diagnostics complete");
+    }
+}
+
+/// Validates config values that are cheap to check without touching the
+/// network: secret strength, URL shape, and CORS origin syntax.
+pub fn validate_config(config: &AppConfig) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    diagnostics.push(if config.jwt_secret.len() >= MIN_JWT_SECRET_LENGTH {
+        Diagnostic::ok("jwt_secret", "meets minimum length")
+    } else {
+        Diagnostic::error(
+            "jwt_secret",
+            format!(
+                "must be at least {MIN_JWT_SECRET_LENGTH} characters, got {}",
+                config.jwt_secret.len()
+            ),
+        )
+    });
+
+    diagnostics.push(match url::Url::parse(&config.database_url) {
+        Ok(_) => Diagnostic::ok("database_url", "is a well-formed URL"),
+        Err(error) => Diagnostic::error("database_url", format!("invalid URL: {error}")),
+    });
+
+    diagnostics.push(validate_cors_origins(&config.cors.allowed_origins));
+
+    diagnostics
+}
+
+fn validate_cors_origins(allowed_origins: &[String]) -> Diagnostic {
+    if allowed_origins.is_empty() {
+        return Diagnostic::warning("cors_allowed_origins", "none configured; cross-origin requests will be rejected");
+    }
+
+    let invalid: Vec<&str> = allowed_origins
+        .iter()
+        .map(String::as_str)
+        .filter(|origin| !is_valid_cors_origin(origin))
+        .collect();
+
+    if invalid.is_empty() {
+        Diagnostic::ok("cors_allowed_origins", format!("{} origin(s) configured", allowed_origins.len()))
+    } else {
+        Diagnostic::error("cors_allowed_origins", format!("invalid origin(s): {}", invalid.join(", ")))
+    }
+}
+
+fn is_valid_cors_origin(origin: &str) -> bool {
+    match url::Url::parse(origin) {
+        Ok(url) => matches!(url.scheme(), "http" | "https") && url.path() == "/" && url.query().is_none(),
+        Err(_) => false,
+    }
+}
+
+/// Confirms the database is actually reachable, beyond the pool having
+/// been constructed successfully.
+pub async fn check_database_connectivity(pool: &PgPool) -> Diagnostic {
+    match sqlx::query("SELECT 1").execute(pool).await {
+        Ok(_) => Diagnostic::ok("database_connectivity", "connected"),
+        Err(error) => Diagnostic::error("database_connectivity", format!("{error}")),
+    }
+}
+
+/// Applies any pending migrations and reports the outcome, so a forgotten
+/// migration surfaces as a boot-time diagnostic rather than a confusing
+/// runtime error the first time the missing column is touched.
+pub async fn check_migrations(pool: &PgPool) -> Diagnostic {
+    match sqlx::migrate!("./migrations").run(pool).await {
+        Ok(_) => Diagnostic::ok("migrations", "up to date"),
+        Err(error) => Diagnostic::error("migrations", format!("{error}")),
+    }
+}
+
+/// Warns (rather than fails) when no admin user exists yet, since a fresh
+/// database legitimately has none until an operator creates the first one.
+pub async fn check_admin_user_exists(pool: &PgPool) -> Diagnostic {
+    let admin_count: Result<i64, _> = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE is_admin = true")
+        .fetch_one(pool)
+        .await;
+
+    match admin_count {
+        Ok(count) if count > 0 => Diagnostic::ok("admin_user", format!("{count} admin user(s) configured")),
+        Ok(_) => Diagnostic::warning(
+            "admin_user",
+            "no admin user exists yet; admin-only endpoints are unreachable until one is created",
+        ),
+        Err(error) => Diagnostic::error("admin_user", format!("{error}")),
+    }
+}
+
+/// Reads the labels of a Postgres native enum type, in declaration order.
+async fn enum_labels(pool: &PgPool, type_name: &str) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT enumlabel FROM pg_enum
+        JOIN pg_type ON pg_type.oid = pg_enum.enumtypid
+        WHERE pg_type.typname = $1
+        ORDER BY enumsortorder
+        "#,
+    )
+    .bind(type_name)
+    .fetch_all(pool)
+    .await
+}
+
+async fn table_columns(pool: &PgPool, table_name: &str) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT column_name FROM information_schema.columns WHERE table_name = $1")
+        .bind(table_name)
+        .fetch_all(pool)
+        .await
+}
+
+fn missing_values(actual: &[String], expected: &[&'static str]) -> Vec<&'static str> {
+    let actual: HashSet<&str> = actual.iter().map(String::as_str).collect();
+    expected.iter().copied().filter(|value| !actual.contains(value)).collect()
+}
+
+/// Confirms the tables, columns and ENUM types the code compiles against
+/// still look the way it expects, so a runtime schema that's drifted from
+/// the binary (e.g. a rolled-back migration, or a manual `ALTER TYPE`)
+/// fails loudly at boot instead of surfacing as a confusing query error
+/// the first time it's hit.
+pub async fn check_schema_matches_expectations(pool: &PgPool) -> Diagnostic {
+    let mut mismatches = Vec::new();
+
+    match enum_labels(pool, "article_status").await {
+        Ok(labels) => {
+            for missing in missing_values(&labels, EXPECTED_ARTICLE_STATUS_LABELS) {
+                mismatches.push(format!("article_status enum is missing value '{missing}'"));
+            }
+        }
+        Err(error) => mismatches.push(format!("could not read article_status enum: {error}")),
+    }
+
+    match enum_labels(pool, "article_license").await {
+        Ok(labels) => {
+            for missing in missing_values(&labels, EXPECTED_ARTICLE_LICENSE_LABELS) {
+                mismatches.push(format!("article_license enum is missing value '{missing}'"));
+            }
+        }
+        Err(error) => mismatches.push(format!("could not read article_license enum: {error}")),
+    }
+
+    match table_columns(pool, "articles").await {
+        Ok(columns) => {
+            for missing in missing_values(&columns, EXPECTED_ARTICLES_COLUMNS) {
+                mismatches.push(format!("articles table is missing column '{missing}'"));
+            }
+        }
+        Err(error) => mismatches.push(format!("could not read articles table columns: {error}")),
+    }
+
+    if mismatches.is_empty() {
+        Diagnostic::ok("schema_introspection", "runtime schema matches the tables/columns/enum types the binary expects")
+    } else {
+        Diagnostic::error("schema_introspection", format!("schema drift detected: {}", mismatches.join("; ")))
+    }
+}
+
+/// Runs every startup diagnostic and returns the aggregated report.
+pub async fn run(config: &AppConfig, pool: &PgPool) -> StartupReport {
+    let mut diagnostics = validate_config(config);
+
+    diagnostics.push(check_database_connectivity(pool).await);
+    diagnostics.push(check_migrations(pool).await);
+    diagnostics.push(check_schema_matches_expectations(pool).await);
+    diagnostics.push(check_admin_user_exists(pool).await);
+
+    StartupReport { diagnostics }
+}
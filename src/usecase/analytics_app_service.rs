@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::NaiveDate;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+
+use crate::domain::repository::AnalyticsRepository;
+use crate::errors::AppError;
+
+#[derive(Clone)]
+pub struct AnalyticsAppService {
+    analytics_repository: Arc<dyn AnalyticsRepository>,
+}
+
+impl AnalyticsAppService {
+    pub fn new(analytics_repository: Arc<dyn AnalyticsRepository>) -> Self {
+        Self { analytics_repository }
+    }
+
+    /// Streams the daily metrics window as CSV chunks, one chunk per row,
+    /// so the full export never needs to be buffered in memory.
+    pub fn export_daily_metrics_csv(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> BoxStream<'static, Result<Bytes, AppError>> {
+        let header = futures::stream::once(async {
+            Ok(Bytes::from_static(b"day,views,reactions,comments,signups\n"))
+        });
+
+        let rows = self
+            .analytics_repository
+            .stream_daily_metrics(from, to)
+            .map(|result| {
+                result.map(|row| {
+                    Bytes::from(format!(
+                        "{},{},{},{},{}\n",
+                        row.day, row.views, row.reactions, row.comments, row.signups
+                    ))
+                })
+            });
+
+        header.chain(rows).boxed()
+    }
+}
@@ -0,0 +1,608 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use uuid::Uuid;
+
+use crate::domain::deadline::RequestDeadline;
+use crate::domain::entities::{
+    Article, ArticleLicense, ArticleLock, ArticlePendingRevision, ArticleStatus, ArticleWithTags, AuditLog,
+    AuthorProfile, NewArticle, NewArticlePendingRevision, NewAuditLog,
+};
+use crate::domain::pagination::{paginate, PagedBody};
+use crate::domain::repository::{
+    ArticleLockRepository, ArticlePendingRevisionRepository, ArticleRepository, ArticleSlugRedirectRepository,
+    AuditLogRepository, CommentRepository, FollowRepository, ImageRepository, TagRepository, UserRepository,
+};
+use crate::domain::validation::{validate_article_fields, ValidationViolation};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::content_derivation;
+use crate::infrastructure::metrics_recorder::{MetricsRecorder, ARTICLES_PUBLISHED_TOTAL};
+use crate::infrastructure::preview_token;
+
+const RECALCULATE_BATCH_DELAY: std::time::Duration = std::time::Duration::from_millis(50);
+const DUPLICATE_SIMILARITY_THRESHOLD: f32 = 0.6;
+const DUPLICATE_CANDIDATE_LIMIT: i64 = 5;
+const ARTICLE_EVENTS_TARGET_TYPE: &str = "article";
+/// How long an acquired edit lock stays valid without being renewed.
+const ARTICLE_LOCK_TTL: chrono::Duration = chrono::Duration::minutes(10);
+
+#[derive(Clone)]
+pub struct ArticleAppService {
+    article_repository: Arc<dyn ArticleRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    pending_revision_repository: Arc<dyn ArticlePendingRevisionRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    image_repository: Arc<dyn ImageRepository>,
+    follow_repository: Arc<dyn FollowRepository>,
+    lock_repository: Arc<dyn ArticleLockRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    slug_redirect_repository: Arc<dyn ArticleSlugRedirectRepository>,
+    jwt_secret: String,
+    metrics_recorder: Arc<MetricsRecorder>,
+}
+
+impl ArticleAppService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        article_repository: Arc<dyn ArticleRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        pending_revision_repository: Arc<dyn ArticlePendingRevisionRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        image_repository: Arc<dyn ImageRepository>,
+        follow_repository: Arc<dyn FollowRepository>,
+        lock_repository: Arc<dyn ArticleLockRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+        slug_redirect_repository: Arc<dyn ArticleSlugRedirectRepository>,
+        jwt_secret: String,
+        metrics_recorder: Arc<MetricsRecorder>,
+    ) -> Self {
+        Self {
+            article_repository,
+            user_repository,
+            tag_repository,
+            pending_revision_repository,
+            audit_log_repository,
+            image_repository,
+            follow_repository,
+            lock_repository,
+            comment_repository,
+            slug_redirect_repository,
+            jwt_secret,
+            metrics_recorder,
+        }
+    }
+
+    /// Acquires (or renews) the article's advisory edit lock for
+    /// `user_id`. Fails with [`AppError::Locked`] if another user
+    /// currently holds an unexpired lock.
+    pub async fn acquire_lock(&self, article_id: Uuid, user_id: Uuid) -> AppResult<ArticleLock> {
+        self.find_by_id(article_id).await?;
+
+        let expires_at = Utc::now() + ARTICLE_LOCK_TTL;
+        match self.lock_repository.acquire(article_id, user_id, expires_at).await? {
+            Some(lock) => Ok(lock),
+            None => Err(self.locked_error(article_id).await),
+        }
+    }
+
+    /// Releases the article's edit lock, if `user_id` holds it.
+    pub async fn release_lock(&self, article_id: Uuid, user_id: Uuid) -> AppResult<()> {
+        self.lock_repository.release(article_id, user_id).await
+    }
+
+    async fn locked_error(&self, article_id: Uuid) -> AppError {
+        match self.lock_repository.find_active(article_id).await {
+            Ok(Some(lock)) => AppError::Locked(format!(
+                "article is locked by another user until {}",
+                lock.expires_at
+            )),
+            _ => AppError::Locked("article is locked by another user".to_string()),
+        }
+    }
+
+    async fn record_event(&self, article_id: Uuid, action: &str, actor_id: Uuid, detail: Option<String>) {
+        let result = self
+            .audit_log_repository
+            .record(NewAuditLog {
+                action: action.to_string(),
+                target_type: ARTICLE_EVENTS_TARGET_TYPE.to_string(),
+                target_id: Some(article_id),
+                actor_id: Some(actor_id),
+                detail,
+            })
+            .await;
+
+        if let Err(error) = result {
+            tracing::warn!(%article_id, action, %error, "failed to record article event");
+        }
+    }
+
+    /// The owning author's activity timeline for this article: creation,
+    /// edits, and publish/unpublish transitions, newest first. Open to the
+    /// article's author or an admin.
+    pub async fn list_events(
+        &self,
+        article_id: Uuid,
+        requesting_user_id: Uuid,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<PagedBody<AuditLog>> {
+        let article = self.find_by_id(article_id).await?;
+        if article.user_id != requesting_user_id {
+            let requester = self
+                .user_repository
+                .find_by_id(requesting_user_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("user {requesting_user_id} not found")))?;
+            if !requester.is_admin {
+                return Err(AppError::Forbidden("not the author of this article".to_string()));
+            }
+        }
+
+        let rows = self
+            .audit_log_repository
+            .find_by_target(ARTICLE_EVENTS_TARGET_TYPE, article_id, per_page, before)
+            .await?;
+
+        Ok(paginate(rows, per_page as usize, |entry| entry.created_at.to_rfc3339()))
+    }
+
+    pub async fn find_all(&self, license: Option<ArticleLicense>) -> AppResult<Vec<Article>> {
+        self.article_repository.find_all(license).await
+    }
+
+    /// Published articles originally posted on this calendar month and day
+    /// in any year, newest first, alongside the total count matching
+    /// regardless of which page was requested, for an "on this day"
+    /// archive widget.
+    pub async fn on_this_day(
+        &self,
+        month: i32,
+        day: i32,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<(PagedBody<Article>, i64)> {
+        if !(1..=12).contains(&month) {
+            return Err(AppError::BadRequest(format!("month must be between 1 and 12, got {month}")));
+        }
+        if !(1..=31).contains(&day) {
+            return Err(AppError::BadRequest(format!("day must be between 1 and 31, got {day}")));
+        }
+
+        let (rows, total) = tokio::try_join!(
+            self.article_repository.find_published_by_month_day(month, day, per_page, before),
+            self.article_repository.count_published_by_month_day(month, day),
+        )?;
+
+        let page = paginate(rows, per_page as usize, |article| article.created_at.to_rfc3339());
+        Ok((page, total))
+    }
+
+    /// Published articles posted during this calendar year and month,
+    /// newest first, alongside the total count matching regardless of
+    /// which page was requested, for date-based archive browsing.
+    pub async fn by_date(
+        &self,
+        year: i32,
+        month: i32,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<(PagedBody<Article>, i64)> {
+        if !(1..=12).contains(&month) {
+            return Err(AppError::BadRequest(format!("month must be between 1 and 12, got {month}")));
+        }
+
+        let (rows, total) = tokio::try_join!(
+            self.article_repository.find_published_by_year_month(year, month, per_page, before),
+            self.article_repository.count_published_by_year_month(year, month),
+        )?;
+
+        let page = paginate(rows, per_page as usize, |article| article.created_at.to_rfc3339());
+        Ok((page, total))
+    }
+
+    /// Same rows as [`Self::find_all`], streamed out as newline-delimited
+    /// JSON chunks so a full-table export never needs the whole result set
+    /// in memory at once.
+    pub fn stream_all_ndjson(&self, license: Option<ArticleLicense>) -> BoxStream<'static, Result<Bytes, AppError>> {
+        self.article_repository
+            .stream_all(license)
+            .map(|result| {
+                let article = result?;
+                let mut line = serde_json::to_vec(&article).map_err(|e| AppError::Internal(e.into()))?;
+                line.push(b'\n');
+                Ok(Bytes::from(line))
+            })
+            .boxed()
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> AppResult<Article> {
+        self.article_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {id} not found")))
+    }
+
+    /// Like [`Self::find_by_id`], but checks that `requesting_user_id` may
+    /// view the article: a published article is open to anyone, but a
+    /// draft or private article is visible only to its owner.
+    pub async fn find_viewable(&self, id: Uuid, requesting_user_id: Option<Uuid>) -> AppResult<Article> {
+        let article = self.find_by_id(id).await?;
+        if article.status == ArticleStatus::Published || requesting_user_id == Some(article.user_id) {
+            return Ok(article);
+        }
+
+        Err(AppError::Forbidden("not authorized to view this article".to_string()))
+    }
+
+    /// Resolves a slug to the id of the article it currently names, whether
+    /// that's its present slug or one it was renamed away from, so a link
+    /// or bookmark made before a rename keeps working.
+    pub async fn resolve_slug(&self, slug: &str) -> AppResult<Uuid> {
+        if let Some(article) = self.article_repository.find_by_slug(slug).await? {
+            return Ok(article.id);
+        }
+
+        let redirect = self
+            .slug_redirect_repository
+            .find_by_old_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("no article found for slug {slug}")))?;
+        Ok(redirect.article_id)
+    }
+
+    /// Like [`Self::find_viewable`], but also fetches the article's tags.
+    /// The two lookups are independent, so they run concurrently instead
+    /// of back-to-back.
+    pub async fn find_by_id_with_tags(&self, id: Uuid, requesting_user_id: Option<Uuid>) -> AppResult<ArticleWithTags> {
+        let (article, tags, comment_summary) = tokio::try_join!(
+            self.find_viewable(id, requesting_user_id),
+            self.tag_repository.find_by_article_id(id),
+            self.comment_repository.thread_summary(id),
+        )?;
+        let (author, follower_count, following_count) = tokio::try_join!(
+            self.user_repository.find_by_id(article.user_id),
+            self.follow_repository.count_followers(article.user_id),
+            self.follow_repository.count_following(article.user_id),
+        )?;
+        let author = author.ok_or_else(|| AppError::NotFound(format!("user {} not found", article.user_id)))?;
+        Ok(ArticleWithTags {
+            article,
+            tags,
+            author: AuthorProfile::new(&author, follower_count, following_count),
+            comment_summary,
+        })
+    }
+
+    /// Mints a short-lived preview token scoped to this article, for an
+    /// SSR frontend's draft preview links. Only the article's author may
+    /// request one.
+    pub async fn issue_preview_token(
+        &self,
+        article_id: Uuid,
+        requesting_user_id: Uuid,
+    ) -> AppResult<(String, DateTime<Utc>)> {
+        let article = self.find_by_id(article_id).await?;
+        if article.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("only the article's author can request a preview token".to_string()));
+        }
+
+        preview_token::issue_preview_token(article_id, &self.jwt_secret)
+            .map_err(AppError::Internal)
+    }
+
+    /// Like [`Self::find_viewable`], but also accepts a preview token
+    /// minted by [`Self::issue_preview_token`] as an alternate way in, so
+    /// an SSR frontend can resolve a preview link without the viewer being
+    /// authenticated as the article's author. Unlike [`Self::find_viewable`],
+    /// a draft or private article without a matching token does not fall
+    /// through to a plain visibility check — ownership or a valid token for
+    /// this exact article is required.
+    pub async fn find_by_id_with_preview_token(
+        &self,
+        id: Uuid,
+        preview_token: Option<&str>,
+        requesting_user_id: Option<Uuid>,
+    ) -> AppResult<Article> {
+        let article = self.find_by_id(id).await?;
+        if article.status == ArticleStatus::Published || requesting_user_id == Some(article.user_id) {
+            return Ok(article);
+        }
+
+        let token = preview_token
+            .ok_or_else(|| AppError::Forbidden("not authorized to view this article".to_string()))?;
+        let token_article_id = preview_token::verify_preview_token(token, &self.jwt_secret)
+            .map_err(|e| AppError::Unauthorized(format!("invalid or expired preview token: {e}")))?;
+        if token_article_id != id {
+            return Err(AppError::Unauthorized("preview token was not issued for this article".to_string()));
+        }
+
+        Ok(article)
+    }
+
+    /// Fallback reading material for a not-found page: up to `limit`
+    /// recently published articles. The article that was actually
+    /// requested no longer exists, so there's nothing left to compute
+    /// title/tag similarity against — recency is the best signal left.
+    pub async fn not_found_suggestions(&self, limit: i64) -> AppResult<Vec<Article>> {
+        self.article_repository.find_recent_published(limit).await
+    }
+
+    /// Published articles from authors `user_id` follows, most recent
+    /// first, for a personalized feed. Empty (not an error) when they
+    /// don't follow anyone yet.
+    pub async fn personalized_feed(&self, user_id: Uuid) -> AppResult<Vec<Article>> {
+        let followed_ids = self.follow_repository.find_followed_ids(user_id).await?;
+        if followed_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.article_repository.find_by_authors(&followed_ids).await
+    }
+
+    /// Applies the requesting user's configured defaults for status and
+    /// category to any field the caller left unset before persisting.
+    pub async fn create(&self, mut new_article: NewArticle, deadline: RequestDeadline) -> AppResult<Article> {
+        let violations = validate_article_fields(&new_article.title, &new_article.body);
+        if !violations.is_empty() {
+            return Err(AppError::BadRequest(join_violations(&violations)));
+        }
+
+        if new_article.status.is_none() || new_article.category_id.is_none() {
+            let author = self
+                .user_repository
+                .find_by_id(new_article.user_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("user {} not found", new_article.user_id)))?;
+
+            new_article.status = new_article.status.or(Some(author.default_article_status));
+            new_article.category_id = new_article.category_id.or(author.default_category_id);
+        }
+
+        if !new_article.allow_duplicate.unwrap_or(false) {
+            let similar = self
+                .article_repository
+                .find_similar(
+                    &new_article.title,
+                    &new_article.body,
+                    DUPLICATE_SIMILARITY_THRESHOLD,
+                    DUPLICATE_CANDIDATE_LIMIT,
+                    deadline,
+                )
+                .await?;
+
+            if !similar.is_empty() {
+                let ids = similar.iter().map(|a| a.id.to_string()).collect::<Vec<_>>().join(", ");
+                return Err(AppError::Conflict(format!(
+                    "found {} similar article(s): {ids}; set allow_duplicate to create anyway",
+                    similar.len()
+                )));
+            }
+        }
+
+        let created = self.article_repository.create(new_article).await?;
+        self.record_event(created.id, "article.created", created.user_id, None).await;
+        if created.status == ArticleStatus::Published {
+            self.metrics_recorder.increment(ARTICLES_PUBLISHED_TOTAL);
+        }
+        Ok(created)
+    }
+
+    /// Persists `article` as given, recording a timeline event for any
+    /// title change and for a status transition into or out of `published`.
+    /// Updates the article, failing with [`AppError::Locked`] if another
+    /// user currently holds its advisory edit lock.
+    pub async fn update(&self, article: Article, user_id: Uuid) -> AppResult<Article> {
+        let violations = validate_article_fields(&article.title, &article.body);
+        if !violations.is_empty() {
+            return Err(AppError::BadRequest(join_violations(&violations)));
+        }
+
+        if let Some(lock) = self.lock_repository.find_active(article.id).await? {
+            if lock.owner_id != user_id {
+                return Err(self.locked_error(article.id).await);
+            }
+        }
+
+        let previous = self.find_by_id(article.id).await?;
+
+        let updated = self.article_repository.update(article).await?;
+
+        if previous.title != updated.title {
+            self.record_event(
+                updated.id,
+                "article.title_changed",
+                updated.user_id,
+                Some(format!("\"{}\" -> \"{}\"", previous.title, updated.title)),
+            )
+            .await;
+        }
+
+        if let (Some(old_slug), Some(new_slug)) = (&previous.slug, &updated.slug) {
+            if old_slug != new_slug {
+                if let Err(error) = self.slug_redirect_repository.record(updated.id, old_slug).await {
+                    tracing::warn!(article_id = %updated.id, %error, "failed to record slug redirect");
+                }
+            }
+        }
+
+        if previous.status != updated.status {
+            let action = if updated.status == ArticleStatus::Published {
+                self.metrics_recorder.increment(ARTICLES_PUBLISHED_TOTAL);
+                "article.published"
+            } else if previous.status == ArticleStatus::Published {
+                "article.unpublished"
+            } else {
+                "article.status_changed"
+            };
+            self.record_event(
+                updated.id,
+                action,
+                updated.user_id,
+                Some(format!("{:?} -> {:?}", previous.status, updated.status)),
+            )
+            .await;
+        }
+
+        Ok(updated)
+    }
+
+    /// Reassigns the article to `new_owner_id`, for when an author leaves a
+    /// multi-author blog. Callable by the current owner or an admin.
+    pub async fn transfer_ownership(
+        &self,
+        article_id: Uuid,
+        new_owner_id: Uuid,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Article> {
+        let article = self.find_by_id(article_id).await?;
+        if article.user_id != requesting_user_id {
+            let requester = self
+                .user_repository
+                .find_by_id(requesting_user_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("user {requesting_user_id} not found")))?;
+            if !requester.is_admin {
+                return Err(AppError::Forbidden("not the author of this article".to_string()));
+            }
+        }
+
+        if new_owner_id == article.user_id {
+            return Err(AppError::BadRequest("article is already owned by this user".to_string()));
+        }
+        self.user_repository
+            .find_by_id(new_owner_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("user {new_owner_id} not found")))?;
+
+        let updated = self.article_repository.update_owner(article_id, new_owner_id).await?;
+        self.record_event(
+            updated.id,
+            "article.ownership_transferred",
+            requesting_user_id,
+            Some(format!("{} -> {}", article.user_id, new_owner_id)),
+        )
+        .await;
+        Ok(updated)
+    }
+
+    async fn require_owner(&self, article_id: Uuid, requesting_user_id: Uuid) -> AppResult<Article> {
+        let article = self.find_by_id(article_id).await?;
+        if article.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the author of this article".to_string()));
+        }
+        Ok(article)
+    }
+
+    /// The author's own not-yet-published edit to this article, if any.
+    /// Readers of the article itself keep seeing the published row
+    /// untouched until [`Self::publish_pending`] is called.
+    pub async fn find_pending(&self, article_id: Uuid, requesting_user_id: Uuid) -> AppResult<ArticlePendingRevision> {
+        self.require_owner(article_id, requesting_user_id).await?;
+        self.pending_revision_repository
+            .find_by_article_id(article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {article_id} has no pending revision")))
+    }
+
+    pub async fn save_pending(
+        &self,
+        new_pending: NewArticlePendingRevision,
+        requesting_user_id: Uuid,
+    ) -> AppResult<ArticlePendingRevision> {
+        self.require_owner(new_pending.article_id, requesting_user_id).await?;
+        self.pending_revision_repository.upsert(new_pending).await
+    }
+
+    /// Copies the pending revision's fields onto the published article and
+    /// discards the pending revision, so the edit becomes the live content.
+    pub async fn publish_pending(&self, article_id: Uuid, requesting_user_id: Uuid) -> AppResult<Article> {
+        let mut article = self.require_owner(article_id, requesting_user_id).await?;
+        let pending = self
+            .pending_revision_repository
+            .find_by_article_id(article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {article_id} has no pending revision")))?;
+
+        article.title = pending.title;
+        article.body = pending.body;
+        article.category_id = pending.category_id;
+        article.license = pending.license;
+        article.attribution = pending.attribution;
+
+        let published = self.article_repository.update(article).await?;
+        self.pending_revision_repository.delete(article_id).await?;
+        self.record_event(published.id, "article.pending_published", requesting_user_id, None)
+            .await;
+        Ok(published)
+    }
+
+    /// Hard-deletes an article and every image attached to it, so discarded
+    /// drafts don't leave orphaned blobs behind; see
+    /// [`crate::usecase::ImageAppService::delete_by_article`] for the
+    /// self-service equivalent that only clears the images. Only the
+    /// article's owner may call this.
+    pub async fn delete(&self, id: Uuid, requesting_user_id: Uuid) -> AppResult<()> {
+        self.require_owner(id, requesting_user_id).await?;
+        self.image_repository.delete_by_article_id(id).await?;
+        self.article_repository.delete(id).await
+    }
+
+    /// Recomputes and persists `slug`/`word_count`/`excerpt` for every
+    /// article, paging through the table so a full recalculation never holds
+    /// more than `batch_size` rows in memory at once. Pauses briefly between
+    /// batches to avoid saturating the database with a backfill.
+    pub async fn recalculate_derived_fields(&self, batch_size: i64) -> AppResult<u64> {
+        let mut processed = 0u64;
+        let mut offset = 0i64;
+
+        loop {
+            let page = self.article_repository.find_page(batch_size, offset).await?;
+            if page.is_empty() {
+                break;
+            }
+
+            for article in &page {
+                let derived = content_derivation::derive(&article.title, &article.body);
+                self.article_repository
+                    .update_derived_fields(article.id, derived.slug, derived.word_count, derived.excerpt)
+                    .await?;
+            }
+
+            processed += page.len() as u64;
+            offset += batch_size;
+            tracing::info!(processed, "recalculated derived fields batch");
+
+            tokio::time::sleep(RECALCULATE_BATCH_DELAY).await;
+        }
+
+        Ok(processed)
+    }
+
+    /// Runs the exact rules [`Self::create`] and [`Self::update`] enforce,
+    /// plus a check that every referenced tag exists, without persisting
+    /// anything. Lets an editor surface every violation at once instead of
+    /// discovering them one submit at a time.
+    pub async fn validate(&self, title: &str, body: &str, tag_ids: &[Uuid]) -> AppResult<Vec<ValidationViolation>> {
+        let mut violations = validate_article_fields(title, body);
+
+        for &tag_id in tag_ids {
+            if self.tag_repository.find_by_id(tag_id).await?.is_none() {
+                violations.push(ValidationViolation {
+                    field: "tag_ids".to_string(),
+                    message: format!("tag {tag_id} does not exist"),
+                });
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+fn join_violations(violations: &[ValidationViolation]) -> String {
+    violations.iter().map(|v| v.message.as_str()).collect::<Vec<_>>().join("; ")
+}
@@ -0,0 +1,139 @@
+use uuid::Uuid;
+
+use crate::config::ObjectStorageConfig;
+use crate::domain::deadline::RequestDeadline;
+use crate::domain::entities::{ArticleStatus, NewArticle};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::concurrency::join_bounded;
+use crate::infrastructure::object_storage;
+use crate::infrastructure::{content_extraction, url_guard};
+use crate::usecase::image_app_service::extension_for_mime_type;
+use crate::usecase::{ArticleAppService, ImageAppService};
+
+/// Caps how many of an imported page's referenced images are downloaded: a
+/// page with hundreds of `<img>` tags would otherwise make one import
+/// request run the full SSRF-checked fetch+redirect+size-cap pipeline
+/// hundreds of times.
+const MAX_IMAGES_PER_IMPORT: usize = 20;
+/// How many of those downloads [`ArticleImportService::import_from_url`]
+/// runs at once, so a page under the cap still can't tie up the handler
+/// fetching them one at a time.
+const IMAGE_FETCH_CONCURRENCY: usize = 4;
+
+/// Imports an article draft from an external URL: fetches the page,
+/// extracts the readable content, converts it to markdown and pulls
+/// referenced images through the image pipeline.
+#[derive(Clone)]
+pub struct ArticleImportService {
+    article_app_service: ArticleAppService,
+    image_app_service: ImageAppService,
+    object_storage: ObjectStorageConfig,
+}
+
+impl ArticleImportService {
+    pub fn new(
+        article_app_service: ArticleAppService,
+        image_app_service: ImageAppService,
+        object_storage: ObjectStorageConfig,
+    ) -> Self {
+        Self {
+            article_app_service,
+            image_app_service,
+            object_storage,
+        }
+    }
+
+    pub async fn import_from_url(
+        &self,
+        source_url: &str,
+        user_id: Uuid,
+        deadline: RequestDeadline,
+    ) -> AppResult<crate::domain::entities::Article> {
+        let html = url_guard::fetch_guarded(source_url).await?;
+
+        let extracted = content_extraction::extract(&html);
+
+        if extracted.image_urls.len() > MAX_IMAGES_PER_IMPORT {
+            tracing::warn!(
+                source_url,
+                total_images = extracted.image_urls.len(),
+                limit = MAX_IMAGES_PER_IMPORT,
+                "imported page referenced more images than the per-import limit; only importing the first batch"
+            );
+        }
+
+        let fetches = extracted
+            .image_urls
+            .iter()
+            .take(MAX_IMAGES_PER_IMPORT)
+            .map(|image_url| {
+                let image_url = image_url.clone();
+                async move {
+                    self.import_referenced_image(&image_url, user_id).await;
+                    Ok::<(), AppError>(())
+                }
+            })
+            .collect();
+        join_bounded(fetches, IMAGE_FETCH_CONCURRENCY).await?;
+
+        self.article_app_service
+            .create(
+                NewArticle {
+                    user_id,
+                    title: extracted.title,
+                    body: extracted.markdown,
+                    status: Some(ArticleStatus::Draft),
+                    category_id: None,
+                    license: None,
+                    attribution: None,
+                    allow_duplicate: None,
+                },
+                deadline,
+            )
+            .await
+    }
+
+    /// Downloads an image an imported article references and registers it
+    /// through the same pipeline a direct upload goes through, rather than
+    /// trusting the external URL as-is: the source page could be edited (or
+    /// taken down) after import, and storing the URL verbatim would leave
+    /// the article's images at the mercy of a host we don't control.
+    /// Failures here (an unreachable image, a type we don't store, storage
+    /// not being configured) are swallowed — a missing inline image
+    /// shouldn't fail the whole import.
+    async fn import_referenced_image(&self, image_url: &str, user_id: Uuid) {
+        let Some(bucket) = self.object_storage.bucket.as_deref() else {
+            return;
+        };
+
+        let Ok((bytes, content_type)) = url_guard::fetch_guarded_bytes(image_url).await else {
+            return;
+        };
+        let Some(content_type) = content_type else {
+            return;
+        };
+        let Ok(extension) = extension_for_mime_type(&content_type) else {
+            return;
+        };
+
+        let image_id = Uuid::new_v4();
+        let key = object_storage::object_key(user_id, image_id, extension);
+        if object_storage::put_object(
+            bucket,
+            &self.object_storage.region,
+            &key,
+            &self.object_storage.access_key_id,
+            &self.object_storage.secret_access_key,
+            bytes,
+            Some(&content_type),
+        )
+        .await
+        .is_err()
+        {
+            return;
+        }
+
+        let stored_url = object_storage::object_url(bucket, &self.object_storage.region, &key);
+        let _ = self.image_app_service.upload(user_id, stored_url, Some(content_type)).await;
+    }
+}
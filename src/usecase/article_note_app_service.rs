@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleNote, NewArticleNote};
+use crate::domain::repository::{ArticleNoteRepository, ArticleRepository};
+use crate::errors::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct ArticleNoteAppService {
+    article_note_repository: Arc<dyn ArticleNoteRepository>,
+    article_repository: Arc<dyn ArticleRepository>,
+}
+
+impl ArticleNoteAppService {
+    pub fn new(
+        article_note_repository: Arc<dyn ArticleNoteRepository>,
+        article_repository: Arc<dyn ArticleRepository>,
+    ) -> Self {
+        Self {
+            article_note_repository,
+            article_repository,
+        }
+    }
+
+    /// Notes are visible only to the article's own author, so every
+    /// operation here first confirms `requesting_user_id` owns the article.
+    async fn require_article_owner(&self, article_id: Uuid, requesting_user_id: Uuid) -> AppResult<()> {
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {article_id} not found")))?;
+
+        if article.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the author of this article".to_string()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn find_by_article_id(&self, article_id: Uuid, requesting_user_id: Uuid) -> AppResult<Vec<ArticleNote>> {
+        self.require_article_owner(article_id, requesting_user_id).await?;
+        self.article_note_repository.find_by_article_id(article_id).await
+    }
+
+    pub async fn create(&self, new_note: NewArticleNote) -> AppResult<ArticleNote> {
+        self.require_article_owner(new_note.article_id, new_note.author_id).await?;
+        self.article_note_repository.create(new_note).await
+    }
+
+    pub async fn update_body(&self, id: Uuid, body: String, requesting_user_id: Uuid) -> AppResult<ArticleNote> {
+        let note = self
+            .article_note_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article note {id} not found")))?;
+
+        if note.author_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the author of this note".to_string()));
+        }
+
+        self.article_note_repository.update_body(id, body).await
+    }
+
+    pub async fn delete(&self, id: Uuid, requesting_user_id: Uuid) -> AppResult<()> {
+        let note = self
+            .article_note_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article note {id} not found")))?;
+
+        if note.author_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the author of this note".to_string()));
+        }
+
+        self.article_note_repository.delete(id).await
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::entities::{Block, NewBlock};
+use crate::domain::repository::BlockRepository;
+use crate::errors::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct BlockAppService {
+    block_repository: Arc<dyn BlockRepository>,
+}
+
+impl BlockAppService {
+    pub fn new(block_repository: Arc<dyn BlockRepository>) -> Self {
+        Self { block_repository }
+    }
+
+    pub async fn find_by_author(&self, author_id: Uuid) -> AppResult<Vec<Block>> {
+        self.block_repository.find_by_author(author_id).await
+    }
+
+    pub async fn create(&self, new_block: NewBlock) -> AppResult<Block> {
+        if new_block.blocked_user_id.is_none() && new_block.blocked_guest_fingerprint.is_none() {
+            return Err(AppError::BadRequest(
+                "either blocked_user_id or blocked_guest_fingerprint must be set".to_string(),
+            ));
+        }
+
+        self.block_repository.create(new_block).await
+    }
+
+    pub async fn delete(&self, author_id: Uuid, id: Uuid) -> AppResult<()> {
+        self.block_repository.delete(author_id, id).await
+    }
+}
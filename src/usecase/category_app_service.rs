@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{Category, CategoryAssignmentResult, CategoryOverview};
+use crate::domain::repository::{ArticleRepository, CategoryRepository};
+use crate::errors::{AppError, AppResult};
+
+/// How many latest articles and top tags a category overview surfaces.
+const OVERVIEW_LIMIT: i64 = 5;
+
+enum BulkOp {
+    Assign,
+    Remove,
+}
+
+#[derive(Clone)]
+pub struct CategoryAppService {
+    category_repository: Arc<dyn CategoryRepository>,
+    article_repository: Arc<dyn ArticleRepository>,
+}
+
+impl CategoryAppService {
+    pub fn new(category_repository: Arc<dyn CategoryRepository>, article_repository: Arc<dyn ArticleRepository>) -> Self {
+        Self {
+            category_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> AppResult<Category> {
+        self.category_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("category {id} not found")))
+    }
+
+    /// Applies a conditional rename, failing with [`AppError::PreconditionFailed`]
+    /// if the category was modified since `expected_updated_at`.
+    pub async fn update_name(
+        &self,
+        id: Uuid,
+        name: String,
+        expected_updated_at: DateTime<Utc>,
+    ) -> AppResult<Category> {
+        self.category_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("category {id} not found")))?;
+
+        self.category_repository
+            .update_name_if_unmodified(id, name, expected_updated_at)
+            .await?
+            .ok_or_else(|| AppError::PreconditionFailed(format!("category {id} was modified since it was last read")))
+    }
+
+    /// Everything a category landing page needs in one response. The three
+    /// supporting queries don't depend on each other, so they run
+    /// concurrently.
+    pub async fn overview(&self, id: Uuid) -> AppResult<CategoryOverview> {
+        let category = self.find_by_id(id).await?;
+
+        let (article_count, latest_articles, top_tags) = tokio::try_join!(
+            self.category_repository.count_articles(id),
+            self.category_repository.find_latest_published_articles(id, OVERVIEW_LIMIT),
+            self.category_repository.find_top_tags(id, OVERVIEW_LIMIT),
+        )?;
+
+        Ok(CategoryOverview {
+            category,
+            article_count,
+            latest_articles,
+            top_tags,
+            child_categories: Vec::new(),
+        })
+    }
+
+    pub async fn assign_to_articles(
+        &self,
+        category_id: Uuid,
+        article_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<CategoryAssignmentResult>> {
+        self.bulk_update(category_id, article_ids, requesting_user_id, BulkOp::Assign).await
+    }
+
+    pub async fn remove_from_articles(
+        &self,
+        category_id: Uuid,
+        article_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<CategoryAssignmentResult>> {
+        self.bulk_update(category_id, article_ids, requesting_user_id, BulkOp::Remove).await
+    }
+
+    /// Checks ownership of every requested article up front so a bad id in
+    /// the list can't abort the rest, then applies the category change to
+    /// the owned subset in one transaction.
+    async fn bulk_update(
+        &self,
+        category_id: Uuid,
+        article_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+        op: BulkOp,
+    ) -> AppResult<Vec<CategoryAssignmentResult>> {
+        self.category_repository
+            .find_by_id(category_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("category {category_id} not found")))?;
+
+        let mut results = Vec::with_capacity(article_ids.len());
+        let mut eligible = Vec::new();
+
+        for article_id in article_ids {
+            match self.article_repository.find_by_id(article_id).await? {
+                Some(article) if article.user_id == requesting_user_id => eligible.push(article_id),
+                Some(_) => results.push(CategoryAssignmentResult {
+                    article_id,
+                    success: false,
+                    error: Some("not the owner of this article".to_string()),
+                }),
+                None => results.push(CategoryAssignmentResult {
+                    article_id,
+                    success: false,
+                    error: Some("article not found".to_string()),
+                }),
+            }
+        }
+
+        if !eligible.is_empty() {
+            match op {
+                BulkOp::Assign => self.category_repository.assign_to_articles(category_id, &eligible).await?,
+                BulkOp::Remove => self.category_repository.remove_from_articles(category_id, &eligible).await?,
+            }
+        }
+
+        results.extend(eligible.into_iter().map(|article_id| CategoryAssignmentResult {
+            article_id,
+            success: true,
+            error: None,
+        }));
+
+        Ok(results)
+    }
+}
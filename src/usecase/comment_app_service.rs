@@ -0,0 +1,374 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    Comment, CommentModerationDetail, CommentModerationStatus, NewAuditLog, NewComment, MAX_USER_AGENT_LENGTH,
+};
+use crate::domain::repository::{ArticleRepository, AuditLogRepository, BlockRepository, CommentRepository};
+use crate::domain::validation::{validate_comment_body, ValidationViolation};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::antispam_scorer::{AntispamInput, AntispamScorer};
+use crate::infrastructure::guest_verification::{self, GuestVerificationSender};
+use crate::infrastructure::metrics_recorder::{
+    MetricsRecorder, COMMENTS_CREATED_TOTAL, DEPRECATED_LEGACY_COMMENT_ID_LOOKUPS_TOTAL,
+};
+
+/// Truncates `value` to at most `max_chars` characters, cutting on a char
+/// boundary rather than a byte boundary.
+fn truncate_chars(value: &str, max_chars: usize) -> String {
+    value.chars().take(max_chars).collect()
+}
+
+/// Must match `paths::COMMENT_VERIFY_EMAIL`; kept as a plain literal here
+/// rather than importing the presentation layer's path constants, since
+/// usecase code must not depend on presentation.
+fn verify_email_url(public_base_url: &str, comment_id: Uuid, token: &str) -> String {
+    format!("{public_base_url}/comments/{comment_id}/verify-email?token={token}")
+}
+
+#[derive(Clone)]
+pub struct CommentAppService {
+    comment_repository: Arc<dyn CommentRepository>,
+    article_repository: Arc<dyn ArticleRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    guest_verification_sender: Arc<dyn GuestVerificationSender>,
+    antispam_scorer: Arc<dyn AntispamScorer>,
+    jwt_secret: String,
+    public_base_url: String,
+    pending_threshold: f32,
+    spam_threshold: f32,
+    metrics_recorder: Arc<MetricsRecorder>,
+}
+
+impl CommentAppService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        article_repository: Arc<dyn ArticleRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        guest_verification_sender: Arc<dyn GuestVerificationSender>,
+        antispam_scorer: Arc<dyn AntispamScorer>,
+        jwt_secret: String,
+        public_base_url: String,
+        pending_threshold: f32,
+        spam_threshold: f32,
+        metrics_recorder: Arc<MetricsRecorder>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            article_repository,
+            block_repository,
+            audit_log_repository,
+            guest_verification_sender,
+            antispam_scorer,
+            jwt_secret,
+            public_base_url,
+            pending_threshold,
+            spam_threshold,
+            metrics_recorder,
+        }
+    }
+
+    /// Maps an antispam score to the moderation status a newly created
+    /// comment should start in.
+    fn moderation_status_for_score(&self, score: f32) -> CommentModerationStatus {
+        if score >= self.spam_threshold {
+            CommentModerationStatus::Spam
+        } else if score >= self.pending_threshold {
+            CommentModerationStatus::Pending
+        } else {
+            CommentModerationStatus::Visible
+        }
+    }
+
+    pub async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Comment>> {
+        self.comment_repository.find_by_article_id(article_id).await
+    }
+
+    /// Resolves a comment id path segment that may be either a UUID (the
+    /// current public id) or an integer (the id a client migrated from the
+    /// old schema may still be linking to), for routes using
+    /// [`crate::presentation::extractors::CommentIdParam`]. Counts each
+    /// integer-id lookup so maintainers can see from
+    /// `GET /admin/performance/metrics` whether this compatibility shim is
+    /// still load-bearing before removing it.
+    pub async fn resolve_id(&self, raw: &str) -> AppResult<Uuid> {
+        if let Ok(id) = Uuid::parse_str(raw) {
+            return Ok(id);
+        }
+
+        let legacy_id = raw
+            .parse::<i32>()
+            .map_err(|_| AppError::BadRequest(format!("\"{raw}\" is not a valid comment id")))?;
+
+        let comment = self
+            .comment_repository
+            .find_by_legacy_id(legacy_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("comment {raw} not found")))?;
+        self.metrics_recorder.increment(DEPRECATED_LEGACY_COMMENT_ID_LOOKUPS_TOTAL);
+        Ok(comment.id)
+    }
+
+    /// Returns an article's comments with any authored by users or guests
+    /// the article's author has blocked filtered out.
+    pub async fn find_by_article_id_visible_to_author(&self, article_id: Uuid) -> AppResult<Vec<Comment>> {
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {article_id} not found")))?;
+
+        let blocks = self.block_repository.find_by_author(article.user_id).await?;
+        let comments = self.comment_repository.find_by_article_id(article_id).await?;
+
+        Ok(comments
+            .into_iter()
+            .filter(|comment| {
+                !blocks.iter().any(|block| {
+                    (comment.user_id.is_some() && comment.user_id == block.blocked_user_id)
+                        || (comment.guest_fingerprint.is_some()
+                            && comment.guest_fingerprint == block.blocked_guest_fingerprint)
+                })
+            })
+            .collect())
+    }
+
+    /// Rejects the comment with a neutral 403 if the article's author has
+    /// blocked this commenter, so blocked users can't infer their status.
+    /// If a guest email was supplied, hashes it for storage and sends a
+    /// one-click verification link; the comment is created immediately
+    /// either way, verification only affects the `guest_email_verified_at`
+    /// flag surfaced to readers afterward.
+    pub async fn create(&self, mut new_comment: NewComment) -> AppResult<Comment> {
+        let violations = validate_comment_body(&new_comment.body);
+        if !violations.is_empty() {
+            return Err(AppError::BadRequest(join_violations(&violations)));
+        }
+
+        let article = self
+            .article_repository
+            .find_by_id(new_comment.article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {} not found", new_comment.article_id)))?;
+
+        let blocked = self
+            .block_repository
+            .is_blocked(
+                article.user_id,
+                new_comment.user_id,
+                new_comment.guest_fingerprint.as_deref(),
+            )
+            .await?;
+
+        if blocked {
+            return Err(AppError::Forbidden("unable to comment on this article".to_string()));
+        }
+
+        if let Some(parent_id) = new_comment.parent_id {
+            let parent = self
+                .comment_repository
+                .find_by_id(parent_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("comment {parent_id} not found")))?;
+
+            if parent.article_id != new_comment.article_id {
+                return Err(AppError::BadRequest(
+                    "parent comment belongs to a different article".to_string(),
+                ));
+            }
+        }
+
+        let guest_email = new_comment.guest_email.take();
+        new_comment.guest_email_hash = guest_email.as_deref().map(guest_verification::hash_email);
+
+        let score = self
+            .antispam_scorer
+            .score(AntispamInput {
+                body: &new_comment.body,
+                author_name: new_comment.guest_name.as_deref(),
+                author_email: guest_email.as_deref(),
+                ip: new_comment.client_ip.as_deref(),
+                user_agent: new_comment.user_agent.as_deref(),
+            })
+            .await?;
+        new_comment.moderation_status = Some(self.moderation_status_for_score(score));
+
+        new_comment.ip_hash = new_comment.client_ip.take().as_deref().map(guest_verification::hash_ip);
+        new_comment.user_agent = new_comment
+            .user_agent
+            .as_deref()
+            .map(|ua| truncate_chars(ua, MAX_USER_AGENT_LENGTH));
+
+        let comment = self.comment_repository.create(new_comment).await?;
+        self.metrics_recorder.increment(COMMENTS_CREATED_TOTAL);
+
+        if let Some(email) = guest_email {
+            let email_hash = comment
+                .guest_email_hash
+                .clone()
+                .expect("guest_email_hash was just set from this email");
+            let token = guest_verification::issue_verification_token(comment.id, &email_hash, &self.jwt_secret)
+                .map_err(AppError::Internal)?;
+            let verify_url = verify_email_url(&self.public_base_url, comment.id, &token);
+            self.guest_verification_sender.send(&email, &verify_url).await?;
+        }
+
+        Ok(comment)
+    }
+
+    /// Applies a conditional edit, failing with [`AppError::PreconditionFailed`]
+    /// if the comment was modified since `expected_updated_at`.
+    pub async fn update_body(
+        &self,
+        id: Uuid,
+        body: String,
+        expected_updated_at: DateTime<Utc>,
+    ) -> AppResult<Comment> {
+        let violations = validate_comment_body(&body);
+        if !violations.is_empty() {
+            return Err(AppError::BadRequest(join_violations(&violations)));
+        }
+
+        let comment = self
+            .comment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("comment {id} not found")))?;
+
+        if comment.deleted_at.is_some() {
+            return Err(AppError::Conflict(format!("comment {id} has been deleted")));
+        }
+
+        self.comment_repository
+            .update_body_if_unmodified(id, body, expected_updated_at)
+            .await?
+            .ok_or_else(|| AppError::PreconditionFailed(format!("comment {id} was modified since it was last read")))
+    }
+
+    pub async fn delete(&self, id: Uuid) -> AppResult<()> {
+        self.comment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("comment {id} not found")))?;
+        self.comment_repository.soft_delete(id).await
+    }
+
+    /// GDPR erasure for a guest commenter: scrubs their name and body text
+    /// across every comment tied to `guest_fingerprint`, leaving the rows in
+    /// place so reply threads stay intact, and records the action taken by
+    /// `actor_id` to the audit log.
+    pub async fn anonymize_guest(&self, guest_fingerprint: &str, actor_id: Uuid) -> AppResult<u64> {
+        let affected = self
+            .comment_repository
+            .anonymize_by_guest_fingerprint(guest_fingerprint)
+            .await?;
+
+        self.audit_log_repository
+            .record(NewAuditLog {
+                action: "comment.anonymize_guest".to_string(),
+                target_type: "guest_fingerprint".to_string(),
+                target_id: None,
+                actor_id: Some(actor_id),
+                detail: Some(format!("anonymized {affected} comment(s)")),
+            })
+            .await?;
+
+        Ok(affected)
+    }
+
+    /// Redeems a one-click verification link, marking the comment's guest
+    /// email as verified. The token's embedded hash must match the hash
+    /// stored on the comment, so a token can't be replayed against a
+    /// different comment even if the same email commented elsewhere.
+    pub async fn verify_guest_email(&self, comment_id: Uuid, token: &str) -> AppResult<Comment> {
+        let (token_comment_id, email_hash) = guest_verification::verify_verification_token(token, &self.jwt_secret)
+            .map_err(|e| AppError::BadRequest(format!("invalid or expired verification token: {e}")))?;
+
+        if token_comment_id != comment_id {
+            return Err(AppError::BadRequest("verification token does not match this comment".to_string()));
+        }
+
+        let verified = self
+            .comment_repository
+            .mark_guest_email_verified(comment_id, &email_hash)
+            .await?;
+
+        if !verified {
+            return Err(AppError::NotFound(format!("comment {comment_id} not found")));
+        }
+
+        self.comment_repository
+            .find_by_id(comment_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("comment {comment_id} not found")))
+    }
+
+    /// Runs the exact rule [`Self::create`] and [`Self::update_body`]
+    /// enforce, without persisting anything, for inline form validation.
+    pub fn validate(&self, body: &str) -> Vec<ValidationViolation> {
+        validate_comment_body(body)
+    }
+
+    /// Comments the antispam scorer held back from public view, for an
+    /// admin moderation queue.
+    pub async fn find_held_for_moderation(&self) -> AppResult<Vec<Comment>> {
+        self.comment_repository.find_held_for_moderation().await
+    }
+
+    /// Releases a held comment to the public (`visible`) or confirms it as
+    /// spam, recording the action to the audit log under `actor_id`.
+    pub async fn set_moderation_status(
+        &self,
+        id: Uuid,
+        status: CommentModerationStatus,
+        actor_id: Uuid,
+    ) -> AppResult<Comment> {
+        let comment = self
+            .comment_repository
+            .set_moderation_status(id, status)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("comment {id} not found")))?;
+
+        self.audit_log_repository
+            .record(NewAuditLog {
+                action: "comment.set_moderation_status".to_string(),
+                target_type: "comment".to_string(),
+                target_id: Some(id),
+                actor_id: Some(actor_id),
+                detail: Some(format!("set to {status:?}")),
+            })
+            .await?;
+
+        Ok(comment)
+    }
+
+    /// The abuse-investigation fields withheld from a comment's normal
+    /// JSON representation, for an admin moderation view.
+    pub async fn find_moderation_detail(&self, id: Uuid) -> AppResult<CommentModerationDetail> {
+        let comment = self
+            .comment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("comment {id} not found")))?;
+        Ok(CommentModerationDetail::from(&comment))
+    }
+
+    /// Clears `ip_hash`/`user_agent` on every comment older than
+    /// `ip_retention`. Returns the number of comments scrubbed.
+    pub async fn scrub_stale_privacy_fields(&self, ip_retention: Duration) -> AppResult<u64> {
+        let cutoff = Utc::now()
+            - chrono::Duration::from_std(ip_retention)
+                .map_err(|e| AppError::Internal(anyhow::anyhow!("invalid ip retention duration: {e}")))?;
+        self.comment_repository.scrub_privacy_fields_before(cutoff).await
+    }
+}
+
+fn join_violations(violations: &[ValidationViolation]) -> String {
+    violations.iter().map(|v| v.message.as_str()).collect::<Vec<_>>().join("; ")
+}
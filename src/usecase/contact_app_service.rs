@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::domain::entities::{ContactMessage, NewContactMessage};
+use crate::domain::repository::ContactMessageRepository;
+use crate::domain::validation::{validate_contact_message, ValidationViolation};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::antispam_scorer::{AntispamInput, AntispamScorer};
+use crate::infrastructure::contact_notifier::ContactNotifier;
+
+fn join_violations(violations: &[ValidationViolation]) -> String {
+    violations.iter().map(|v| v.message.as_str()).collect::<Vec<_>>().join("; ")
+}
+
+#[derive(Clone)]
+pub struct ContactAppService {
+    contact_message_repository: Arc<dyn ContactMessageRepository>,
+    antispam_scorer: Arc<dyn AntispamScorer>,
+    contact_notifier: Arc<dyn ContactNotifier>,
+    spam_threshold: f32,
+}
+
+impl ContactAppService {
+    pub fn new(
+        contact_message_repository: Arc<dyn ContactMessageRepository>,
+        antispam_scorer: Arc<dyn AntispamScorer>,
+        contact_notifier: Arc<dyn ContactNotifier>,
+        spam_threshold: f32,
+    ) -> Self {
+        Self {
+            contact_message_repository,
+            antispam_scorer,
+            contact_notifier,
+            spam_threshold,
+        }
+    }
+
+    /// Scores the submission for spam, persists it either way (so an admin
+    /// can audit false positives), and notifies the site admin unless it
+    /// scored as spam.
+    pub async fn submit(&self, message: NewContactMessage) -> AppResult<ContactMessage> {
+        let violations = validate_contact_message(&message.name, &message.email, &message.message);
+        if !violations.is_empty() {
+            return Err(AppError::BadRequest(join_violations(&violations)));
+        }
+
+        let score = self
+            .antispam_scorer
+            .score(AntispamInput {
+                body: &message.message,
+                author_name: Some(&message.name),
+                author_email: Some(&message.email),
+                ip: message.client_ip.as_deref(),
+                user_agent: message.user_agent.as_deref(),
+            })
+            .await?;
+        let is_spam = score >= self.spam_threshold;
+
+        let created = self.contact_message_repository.create(message, is_spam).await?;
+
+        if !is_spam {
+            self.contact_notifier.notify(&created).await?;
+        }
+
+        Ok(created)
+    }
+
+    /// Runs the exact rule [`Self::submit`] enforces, without persisting
+    /// anything, so a contact form can show violations inline.
+    pub fn validate(&self, name: &str, email: &str, message: &str) -> Vec<ValidationViolation> {
+        validate_contact_message(name, email, message)
+    }
+
+    pub async fn find_all(&self) -> AppResult<Vec<ContactMessage>> {
+        self.contact_message_repository.find_all().await
+    }
+}
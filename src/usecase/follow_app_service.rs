@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::entities::FollowStatus;
+use crate::domain::repository::{FollowRepository, UserRepository};
+use crate::errors::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct FollowAppService {
+    follow_repository: Arc<dyn FollowRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl FollowAppService {
+    pub fn new(follow_repository: Arc<dyn FollowRepository>, user_repository: Arc<dyn UserRepository>) -> Self {
+        Self {
+            follow_repository,
+            user_repository,
+        }
+    }
+
+    async fn status(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<FollowStatus> {
+        let (is_following, follower_count, following_count) = tokio::try_join!(
+            self.follow_repository.is_following(follower_id, followed_id),
+            self.follow_repository.count_followers(followed_id),
+            self.follow_repository.count_following(followed_id),
+        )?;
+        Ok(FollowStatus {
+            followed_id,
+            is_following,
+            follower_count,
+            following_count,
+        })
+    }
+
+    pub async fn follow(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<FollowStatus> {
+        if follower_id == followed_id {
+            return Err(AppError::BadRequest("cannot follow yourself".to_string()));
+        }
+        self.user_repository
+            .find_by_id(followed_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("user {followed_id} not found")))?;
+
+        self.follow_repository.follow(follower_id, followed_id).await?;
+        self.status(follower_id, followed_id).await
+    }
+
+    pub async fn unfollow(&self, follower_id: Uuid, followed_id: Uuid) -> AppResult<FollowStatus> {
+        self.follow_repository.unfollow(follower_id, followed_id).await?;
+        self.status(follower_id, followed_id).await
+    }
+}
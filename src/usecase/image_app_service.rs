@@ -0,0 +1,390 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::config::ObjectStorageConfig;
+use crate::domain::entities::{ArticleStatus, Image, ImageDeletionResult, ImageListFilter, ImageProcessingStatus};
+use crate::domain::repository::{ArticleRepository, ImageRepository};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::alt_text_suggester::AltTextSuggester;
+use crate::infrastructure::image_url_provider::{ImageTransform, ImageUrlProvider};
+use crate::infrastructure::metrics_recorder::{MetricsRecorder, IMAGES_UPLOADED_BYTES};
+use crate::infrastructure::object_storage;
+
+/// Stands in for the real job queue worker: long enough that callers can
+/// observe `pending` by polling, short enough not to matter in practice.
+const PROCESSING_DELAY: Duration = Duration::from_secs(2);
+
+/// Above this size a presigned direct upload is worth it over the proxied
+/// multipart path; also enforced as the hard ceiling on what [`Self::confirm_upload`]
+/// will register, so a compromised or buggy client can't park arbitrarily
+/// large objects on the bucket under our name.
+const MAX_DIRECT_UPLOAD_BYTES: u64 = 50 * 1024 * 1024;
+
+/// What a client needs to perform a direct upload and later confirm it:
+/// a presigned PUT URL for the object itself, and an opaque token proving
+/// the presign call was ours when the client comes back to confirm.
+#[derive(Debug, Serialize)]
+pub struct PresignedUpload {
+    pub image_id: Uuid,
+    pub upload_url: String,
+    pub upload_token: String,
+    pub expires_in_secs: u64,
+}
+
+#[derive(Clone)]
+pub struct ImageAppService {
+    image_repository: Arc<dyn ImageRepository>,
+    article_repository: Arc<dyn ArticleRepository>,
+    image_url_provider: Arc<dyn ImageUrlProvider>,
+    object_storage: ObjectStorageConfig,
+    jwt_secret: String,
+    alt_text_suggester: Arc<dyn AltTextSuggester>,
+    metrics_recorder: Arc<MetricsRecorder>,
+}
+
+impl ImageAppService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        image_repository: Arc<dyn ImageRepository>,
+        article_repository: Arc<dyn ArticleRepository>,
+        image_url_provider: Arc<dyn ImageUrlProvider>,
+        object_storage: ObjectStorageConfig,
+        jwt_secret: String,
+        alt_text_suggester: Arc<dyn AltTextSuggester>,
+        metrics_recorder: Arc<MetricsRecorder>,
+    ) -> Self {
+        Self {
+            image_repository,
+            article_repository,
+            image_url_provider,
+            object_storage,
+            jwt_secret,
+            alt_text_suggester,
+            metrics_recorder,
+        }
+    }
+
+    /// Kicks off the background alt-text suggestion job for a newly
+    /// registered image, mirroring how [`Self::upload`] hands variant
+    /// generation off to [`PROCESSING_DELAY`]'s stand-in job queue worker.
+    fn spawn_alt_text_suggestion(&self, image_id: Uuid, image_url: String) {
+        let suggester = self.alt_text_suggester.clone();
+        let image_repository = self.image_repository.clone();
+        tokio::spawn(async move {
+            match suggester.suggest(&image_url).await {
+                Ok(Some(suggested_alt_text)) => {
+                    if let Err(error) = image_repository
+                        .update_suggested_alt_text(image_id, suggested_alt_text)
+                        .await
+                    {
+                        tracing::error!(%image_id, %error, "failed to store suggested alt text");
+                    }
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    tracing::error!(%image_id, %error, "failed to get alt text suggestion");
+                }
+            }
+        });
+    }
+
+    /// Rewrites `image.url` through the configured CDN/image proxy, if any;
+    /// local serving is the fallback when no proxy is configured.
+    fn with_resolved_url(&self, mut image: Image, transform: &ImageTransform) -> Image {
+        image.url = self.image_url_provider.resolve(&image.url, transform);
+        image
+    }
+
+    pub async fn find_by_article_id(&self, article_id: Uuid) -> AppResult<Vec<Image>> {
+        let images = self.image_repository.find_by_article_id(article_id).await?;
+        Ok(images
+            .into_iter()
+            .map(|image| self.with_resolved_url(image, &ImageTransform::default()))
+            .collect())
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> AppResult<Image> {
+        let image = self
+            .image_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("image {id} not found")))?;
+        Ok(self.with_resolved_url(image, &ImageTransform::default()))
+    }
+
+    pub async fn create(&self, image: Image) -> AppResult<Image> {
+        let created = self.image_repository.create(image).await?;
+        Ok(self.with_resolved_url(created, &ImageTransform::default()))
+    }
+
+    /// Registers an uploaded image and hands it off to background
+    /// processing (variant/transcode generation), returning immediately
+    /// with `processing_status: pending` so the upload call doesn't block.
+    pub async fn upload(&self, user_id: Uuid, url: String, mime_type: Option<String>) -> AppResult<Image> {
+        let now = Utc::now();
+        let image = self
+            .image_repository
+            .create(Image {
+                id: Uuid::new_v4(),
+                article_id: None,
+                user_id,
+                url,
+                mime_type,
+                processing_status: ImageProcessingStatus::Pending,
+                suggested_alt_text: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+
+        let image_repository = self.image_repository.clone();
+        let image_id = image.id;
+        tokio::spawn(async move {
+            tokio::time::sleep(PROCESSING_DELAY).await;
+            if let Err(error) = image_repository
+                .update_processing_status(image_id, ImageProcessingStatus::Ready)
+                .await
+            {
+                tracing::error!(%image_id, %error, "failed to mark image as ready after processing");
+            }
+        });
+        self.spawn_alt_text_suggestion(image.id, image.url.clone());
+
+        Ok(self.with_resolved_url(image, &ImageTransform::default()))
+    }
+
+    pub async fn find_by_owner(
+        &self,
+        user_id: Uuid,
+        filter: ImageListFilter,
+        transform: ImageTransform,
+    ) -> AppResult<Vec<Image>> {
+        let images = self.image_repository.find_by_owner(user_id, filter).await?;
+        Ok(images
+            .into_iter()
+            .map(|image| self.with_resolved_url(image, &transform))
+            .collect())
+    }
+
+    /// Reattaches an image to a different article owned by the same user, or
+    /// detaches it into the unattached library when `article_id` is `None`.
+    pub async fn reassign(
+        &self,
+        image_id: Uuid,
+        requesting_user_id: Uuid,
+        article_id: Option<Uuid>,
+    ) -> AppResult<Image> {
+        let image = self
+            .image_repository
+            .find_by_id(image_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("image {image_id} not found")))?;
+
+        if image.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the owner of this image".to_string()));
+        }
+
+        if let Some(article_id) = article_id {
+            let article = self
+                .article_repository
+                .find_by_id(article_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("article {article_id} not found")))?;
+
+            if article.user_id != requesting_user_id {
+                return Err(AppError::Forbidden("not the owner of the target article".to_string()));
+            }
+        }
+
+        let updated = self.image_repository.update_article_id(image_id, article_id).await?;
+        Ok(self.with_resolved_url(updated, &ImageTransform::default()))
+    }
+
+    pub async fn delete(&self, id: Uuid) -> AppResult<()> {
+        self.image_repository.delete(id).await
+    }
+
+    /// Deletes every image attached to `article_id` in one transaction, so
+    /// discarding a draft (or purging an article outright) doesn't leave
+    /// orphaned blobs behind. Only the article's owner may call this.
+    pub async fn delete_by_article(&self, article_id: Uuid, requesting_user_id: Uuid) -> AppResult<Vec<ImageDeletionResult>> {
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {article_id} not found")))?;
+
+        if article.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the owner of this article".to_string()));
+        }
+
+        let deleted_ids = self.image_repository.delete_by_article_id(article_id).await?;
+        Ok(deleted_ids
+            .into_iter()
+            .map(|image_id| ImageDeletionResult {
+                image_id,
+                success: true,
+                error: None,
+            })
+            .collect())
+    }
+
+    /// Like [`Self::find_by_id`], but checks that `requesting_user_id` may
+    /// view the image's parent article before returning it: published
+    /// articles are open to anyone, but an image attached to a draft or
+    /// private article (or not yet attached to any article) is visible
+    /// only to its owner.
+    pub async fn find_viewable(&self, id: Uuid, requesting_user_id: Option<Uuid>) -> AppResult<Image> {
+        let image = self
+            .image_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("image {id} not found")))?;
+
+        let viewable = match image.article_id {
+            Some(article_id) => {
+                let article = self
+                    .article_repository
+                    .find_by_id(article_id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound(format!("article {article_id} not found")))?;
+                article.status == ArticleStatus::Published || requesting_user_id == Some(article.user_id)
+            }
+            None => requesting_user_id == Some(image.user_id),
+        };
+
+        if !viewable {
+            return Err(AppError::Forbidden("not authorized to view this image".to_string()));
+        }
+
+        Ok(self.with_resolved_url(image, &ImageTransform::default()))
+    }
+
+    /// Presigns a direct-to-bucket upload for a large image, bypassing the
+    /// proxied multipart path entirely. The returned `upload_token` must be
+    /// handed back to [`Self::confirm_upload`] once the client has PUT the
+    /// object to `upload_url`; nothing is registered in the image library
+    /// until then.
+    pub fn presign_upload(&self, user_id: Uuid, mime_type: &str) -> AppResult<PresignedUpload> {
+        let bucket = self.object_storage.bucket.as_deref().ok_or_else(|| {
+            AppError::BadRequest("direct uploads are not configured for this server".to_string())
+        })?;
+        let extension = extension_for_mime_type(mime_type)?;
+
+        let image_id = Uuid::new_v4();
+        let key = object_storage::object_key(user_id, image_id, extension);
+        let upload_url = object_storage::presign(
+            "PUT",
+            bucket,
+            &self.object_storage.region,
+            &key,
+            &self.object_storage.access_key_id,
+            &self.object_storage.secret_access_key,
+            self.object_storage.presign_ttl,
+            Utc::now(),
+        );
+        let upload_token = object_storage::issue_upload_token(
+            image_id,
+            user_id,
+            &key,
+            mime_type,
+            &self.jwt_secret,
+            self.object_storage.presign_ttl,
+        )
+        .map_err(AppError::Internal)?;
+
+        Ok(PresignedUpload {
+            image_id,
+            upload_url,
+            upload_token,
+            expires_in_secs: self.object_storage.presign_ttl.as_secs(),
+        })
+    }
+
+    /// Confirms a presigned direct upload by `HEAD`ing the object the
+    /// client claims to have PUT, and registers it as a new image once its
+    /// real size and content type check out. `requesting_user_id` must
+    /// match the user the upload was presigned for.
+    pub async fn confirm_upload(&self, upload_token: &str, requesting_user_id: Uuid) -> AppResult<Image> {
+        let bucket = self.object_storage.bucket.as_deref().ok_or_else(|| {
+            AppError::BadRequest("direct uploads are not configured for this server".to_string())
+        })?;
+        let pending = object_storage::verify_upload_token(upload_token, &self.jwt_secret)
+            .map_err(|e| AppError::BadRequest(format!("invalid or expired upload token: {e}")))?;
+
+        if pending.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the owner of this upload".to_string()));
+        }
+
+        let metadata = object_storage::head_object(
+            bucket,
+            &self.object_storage.region,
+            &pending.key,
+            &self.object_storage.access_key_id,
+            &self.object_storage.secret_access_key,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest("no object was found at the presigned upload location".to_string())
+        })?;
+
+        let content_length = metadata
+            .content_length
+            .ok_or_else(|| AppError::BadRequest("uploaded object is missing a Content-Length".to_string()))?;
+        if content_length == 0 {
+            return Err(AppError::BadRequest("uploaded object is empty".to_string()));
+        }
+        if content_length > MAX_DIRECT_UPLOAD_BYTES {
+            return Err(AppError::BadRequest(format!(
+                "uploaded object is {content_length} bytes, over the {MAX_DIRECT_UPLOAD_BYTES} byte limit"
+            )));
+        }
+        if let Some(content_type) = &metadata.content_type {
+            if content_type != &pending.mime_type {
+                return Err(AppError::BadRequest(format!(
+                    "uploaded object's content type \"{content_type}\" does not match the presigned \"{}\"",
+                    pending.mime_type
+                )));
+            }
+        }
+
+        let now = Utc::now();
+        let url = object_storage::object_url(bucket, &self.object_storage.region, &pending.key);
+        let image = self
+            .image_repository
+            .create(Image {
+                id: pending.image_id,
+                article_id: None,
+                user_id: requesting_user_id,
+                url,
+                mime_type: Some(pending.mime_type),
+                processing_status: ImageProcessingStatus::Ready,
+                suggested_alt_text: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+        self.metrics_recorder.increment_by(IMAGES_UPLOADED_BYTES, content_length);
+        self.spawn_alt_text_suggestion(image.id, image.url.clone());
+
+        Ok(self.with_resolved_url(image, &ImageTransform::default()))
+    }
+}
+
+/// Maps a direct-upload mime type to the file extension its object key is
+/// given in the bucket, matching what [`crate::presentation::extractors::ValidatedImage`]
+/// accepts for the proxied multipart path; also doubles as the allow-list
+/// for [`ImageAppService::presign_upload`].
+pub(crate) fn extension_for_mime_type(mime_type: &str) -> AppResult<&'static str> {
+    match mime_type {
+        "image/png" => Ok("png"),
+        "image/jpeg" => Ok("jpg"),
+        "image/webp" => Ok("webp"),
+        "image/gif" => Ok("gif"),
+        other => Err(AppError::BadRequest(format!("unsupported image type \"{other}\""))),
+    }
+}
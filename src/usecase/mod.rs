@@ -0,0 +1,31 @@
+pub mod analytics_app_service;
+pub mod article_app_service;
+pub mod article_import_service;
+pub mod article_note_app_service;
+pub mod block_app_service;
+pub mod category_app_service;
+pub mod comment_app_service;
+pub mod contact_app_service;
+pub mod follow_app_service;
+pub mod image_app_service;
+pub mod retention_app_service;
+pub mod sitemap_app_service;
+pub mod tag_app_service;
+pub mod unfurl_app_service;
+pub mod user_app_service;
+
+pub use analytics_app_service::AnalyticsAppService;
+pub use article_app_service::ArticleAppService;
+pub use article_import_service::ArticleImportService;
+pub use article_note_app_service::ArticleNoteAppService;
+pub use block_app_service::BlockAppService;
+pub use category_app_service::CategoryAppService;
+pub use comment_app_service::CommentAppService;
+pub use contact_app_service::ContactAppService;
+pub use follow_app_service::FollowAppService;
+pub use image_app_service::{ImageAppService, PresignedUpload};
+pub use retention_app_service::RetentionAppService;
+pub use sitemap_app_service::SitemapAppService;
+pub use tag_app_service::TagAppService;
+pub use unfurl_app_service::UnfurlAppService;
+pub use user_app_service::UserAppService;
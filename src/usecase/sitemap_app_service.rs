@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::entities::SitemapEntry;
+use crate::domain::repository::SitemapRepository;
+use crate::errors::AppResult;
+
+#[derive(Clone)]
+pub struct SitemapAppService {
+    sitemap_repository: Arc<dyn SitemapRepository>,
+}
+
+impl SitemapAppService {
+    pub fn new(sitemap_repository: Arc<dyn SitemapRepository>) -> Self {
+        Self { sitemap_repository }
+    }
+
+    /// Every category with at least one published article, and how many, so
+    /// the sitemap index can list exactly as many paginated files as each
+    /// category needs.
+    pub async fn category_article_counts(&self) -> AppResult<Vec<(Uuid, i64)>> {
+        self.sitemap_repository.category_article_counts().await
+    }
+
+    pub async fn tag_article_counts(&self) -> AppResult<Vec<(Uuid, i64)>> {
+        self.sitemap_repository.tag_article_counts().await
+    }
+
+    pub async fn category_page(&self, category_id: Uuid, limit: i64, offset: i64) -> AppResult<Vec<SitemapEntry>> {
+        self.sitemap_repository.find_page_by_category(category_id, limit, offset).await
+    }
+
+    pub async fn tag_page(&self, tag_id: Uuid, limit: i64, offset: i64) -> AppResult<Vec<SitemapEntry>> {
+        self.sitemap_repository.find_page_by_tag(tag_id, limit, offset).await
+    }
+}
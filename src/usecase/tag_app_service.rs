@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{Article, ArticleStatus, Tag, TagAttachmentResult};
+use crate::domain::pagination::{paginate, PagedBody};
+use crate::domain::repository::{ArticleRepository, TagRepository};
+use crate::errors::{AppError, AppResult};
+
+enum BulkOp {
+    Attach,
+    Detach,
+}
+
+#[derive(Clone)]
+pub struct TagAppService {
+    tag_repository: Arc<dyn TagRepository>,
+    article_repository: Arc<dyn ArticleRepository>,
+}
+
+impl TagAppService {
+    pub fn new(tag_repository: Arc<dyn TagRepository>, article_repository: Arc<dyn ArticleRepository>) -> Self {
+        Self {
+            tag_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn attach_to_articles(
+        &self,
+        tag_id: Uuid,
+        article_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<TagAttachmentResult>> {
+        self.bulk_update(tag_id, article_ids, requesting_user_id, BulkOp::Attach).await
+    }
+
+    pub async fn detach_from_articles(
+        &self,
+        tag_id: Uuid,
+        article_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<TagAttachmentResult>> {
+        self.bulk_update(tag_id, article_ids, requesting_user_id, BulkOp::Detach).await
+    }
+
+    /// Replaces the article's tag set with exactly `tag_ids`, diffing
+    /// against what's already attached instead of clearing and reinserting
+    /// everything, so an author saving the same tags repeatedly doesn't
+    /// churn rows or lose `created_at` on unchanged attachments.
+    pub async fn set_article_tags(
+        &self,
+        article_id: Uuid,
+        tag_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<Tag>> {
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("article {article_id} not found")))?;
+        if article.user_id != requesting_user_id {
+            return Err(AppError::Forbidden("not the author of this article".to_string()));
+        }
+
+        for &tag_id in &tag_ids {
+            self.tag_repository
+                .find_by_id(tag_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("tag {tag_id} not found")))?;
+        }
+
+        self.tag_repository.sync_article_tags(article_id, &tag_ids).await?;
+        self.tag_repository.find_by_article_id(article_id).await
+    }
+
+    /// Published articles carrying this tag, newest first, alongside the
+    /// total count matching regardless of which page was requested.
+    pub async fn list_articles(
+        &self,
+        tag_id: Uuid,
+        per_page: i64,
+        before: Option<DateTime<Utc>>,
+    ) -> AppResult<(PagedBody<Article>, i64)> {
+        self.tag_repository
+            .find_by_id(tag_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("tag {tag_id} not found")))?;
+
+        let (rows, total) = tokio::try_join!(
+            self.tag_repository
+                .find_articles_page(tag_id, Some(ArticleStatus::Published), per_page, before),
+            self.tag_repository.count_articles(tag_id, Some(ArticleStatus::Published)),
+        )?;
+
+        let page = paginate(rows, per_page as usize, |article| article.created_at.to_rfc3339());
+        Ok((page, total))
+    }
+
+    /// Checks ownership of every requested article up front so a bad id in
+    /// the list can't abort the rest, then applies the tag change to the
+    /// owned subset in one transaction.
+    async fn bulk_update(
+        &self,
+        tag_id: Uuid,
+        article_ids: Vec<Uuid>,
+        requesting_user_id: Uuid,
+        op: BulkOp,
+    ) -> AppResult<Vec<TagAttachmentResult>> {
+        self.tag_repository
+            .find_by_id(tag_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("tag {tag_id} not found")))?;
+
+        let mut results = Vec::with_capacity(article_ids.len());
+        let mut eligible = Vec::new();
+
+        for article_id in article_ids {
+            match self.article_repository.find_by_id(article_id).await? {
+                Some(article) if article.user_id == requesting_user_id => eligible.push(article_id),
+                Some(_) => results.push(TagAttachmentResult {
+                    article_id,
+                    success: false,
+                    error: Some("not the owner of this article".to_string()),
+                }),
+                None => results.push(TagAttachmentResult {
+                    article_id,
+                    success: false,
+                    error: Some("article not found".to_string()),
+                }),
+            }
+        }
+
+        if !eligible.is_empty() {
+            match op {
+                BulkOp::Attach => self.tag_repository.attach_to_articles(tag_id, &eligible).await?,
+                BulkOp::Detach => self.tag_repository.detach_from_articles(tag_id, &eligible).await?,
+            }
+        }
+
+        results.extend(eligible.into_iter().map(|article_id| TagAttachmentResult {
+            article_id,
+            success: true,
+            error: None,
+        }));
+
+        Ok(results)
+    }
+}
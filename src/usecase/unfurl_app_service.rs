@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use url::Url;
+
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::rate_limiter::RateLimiter;
+use crate::infrastructure::ttl_cache::TtlCache;
+use crate::infrastructure::{opengraph, url_guard};
+
+const CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+const REQUESTS_PER_MINUTE_PER_DOMAIN: u32 = 30;
+
+/// OpenGraph-derived preview of an external link, cached by URL so the
+/// editor and rendered articles can show rich link cards without refetching
+/// the source page on every view.
+#[derive(Debug, Clone, Serialize)]
+pub struct LinkPreview {
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct UnfurlAppService {
+    cache: std::sync::Arc<TtlCache<LinkPreview>>,
+    domain_rate_limiter: std::sync::Arc<RateLimiter>,
+}
+
+impl UnfurlAppService {
+    pub fn new() -> Self {
+        Self {
+            cache: std::sync::Arc::new(TtlCache::new(CACHE_TTL)),
+            domain_rate_limiter: std::sync::Arc::new(RateLimiter::new()),
+        }
+    }
+
+    pub async fn unfurl(&self, url: &str) -> AppResult<LinkPreview> {
+        if let Some(cached) = self.cache.get(url) {
+            return Ok(cached);
+        }
+
+        let parsed = Url::parse(url).map_err(|_| AppError::BadRequest("invalid url".to_string()))?;
+        let domain = parsed
+            .host_str()
+            .ok_or_else(|| AppError::BadRequest("url has no host".to_string()))?
+            .to_string();
+
+        if !self.domain_rate_limiter.check(&domain, REQUESTS_PER_MINUTE_PER_DOMAIN) {
+            return Err(AppError::TooManyRequests(format!(
+                "too many unfurl requests for domain {domain}"
+            )));
+        }
+
+        let html = url_guard::fetch_guarded(url).await?;
+
+        let metadata = opengraph::extract(&html);
+        let preview = LinkPreview {
+            url: url.to_string(),
+            title: metadata.title,
+            description: metadata.description,
+            image_url: metadata.image_url,
+        };
+
+        self.cache.insert(url.to_string(), preview.clone());
+
+        Ok(preview)
+    }
+}
+
+impl Default for UnfurlAppService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::entities::{ArticleStatus, AuthorProfile, User, UserPreferences};
+use crate::domain::repository::{FollowRepository, UserRepository};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::datetime_format::is_valid_timezone;
+use crate::infrastructure::url_guard::is_publicly_routable;
+
+/// Locale tags this backend knows how to format dates for; see
+/// [`crate::infrastructure::datetime_format`].
+const SUPPORTED_LOCALES: &[&str] = &["en", "ja"];
+
+#[derive(Clone)]
+pub struct UserAppService {
+    user_repository: Arc<dyn UserRepository>,
+    follow_repository: Arc<dyn FollowRepository>,
+}
+
+impl UserAppService {
+    pub fn new(user_repository: Arc<dyn UserRepository>, follow_repository: Arc<dyn FollowRepository>) -> Self {
+        Self {
+            user_repository,
+            follow_repository,
+        }
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> AppResult<User> {
+        self.user_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("user {id} not found")))
+    }
+
+    pub async fn get_preferences(&self, user_id: Uuid) -> AppResult<UserPreferences> {
+        let user = self.find_by_id(user_id).await?;
+        Ok(UserPreferences::from(&user))
+    }
+
+    async fn build_profile(&self, user: User) -> AppResult<AuthorProfile> {
+        let (follower_count, following_count) = tokio::try_join!(
+            self.follow_repository.count_followers(user.id),
+            self.follow_repository.count_following(user.id),
+        )?;
+        Ok(AuthorProfile::new(&user, follower_count, following_count))
+    }
+
+    pub async fn get_profile(&self, user_id: Uuid) -> AppResult<AuthorProfile> {
+        let user = self.find_by_id(user_id).await?;
+        self.build_profile(user).await
+    }
+
+    /// Updates the caller's own display metadata. `website` and every entry
+    /// in `social_links` must be a publicly routable http(s) URL, rejecting
+    /// the same internal/loopback targets an unfurl or import request would.
+    pub async fn update_profile(
+        &self,
+        user_id: Uuid,
+        bio: Option<String>,
+        website: Option<String>,
+        social_links: Vec<String>,
+    ) -> AppResult<AuthorProfile> {
+        if let Some(website) = &website {
+            if !is_publicly_routable(website) {
+                return Err(AppError::BadRequest(format!("website \"{website}\" is not a valid public URL")));
+            }
+        }
+        for link in &social_links {
+            if !is_publicly_routable(link) {
+                return Err(AppError::BadRequest(format!("social link \"{link}\" is not a valid public URL")));
+            }
+        }
+
+        let user = self.user_repository.update_profile(user_id, bio, website, social_links).await?;
+        self.build_profile(user).await
+    }
+
+    /// Admin-only: grants or revokes a user's verification badge.
+    pub async fn set_verified(&self, user_id: Uuid, is_verified: bool) -> AppResult<AuthorProfile> {
+        let user = self.user_repository.set_verified(user_id, is_verified).await?;
+        self.build_profile(user).await
+    }
+
+    pub async fn update_preferences(
+        &self,
+        user_id: Uuid,
+        default_article_status: ArticleStatus,
+        default_category_id: Option<Uuid>,
+        timezone: String,
+        locale: String,
+    ) -> AppResult<UserPreferences> {
+        if !is_valid_timezone(&timezone) {
+            return Err(AppError::BadRequest(format!("\"{timezone}\" is not a recognized timezone")));
+        }
+        if !SUPPORTED_LOCALES.contains(&locale.as_str()) {
+            return Err(AppError::BadRequest(format!("\"{locale}\" is not a supported locale")));
+        }
+
+        let user = self
+            .user_repository
+            .update_preferences(user_id, default_article_status, default_category_id, timezone, locale)
+            .await?;
+        Ok(UserPreferences::from(&user))
+    }
+}